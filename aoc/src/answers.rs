@@ -0,0 +1,78 @@
+use std::fmt;
+use std::io;
+
+use utils::{read_file, Answer};
+
+/// Outcome of checking one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { found: Answer, expected: String },
+    Missing { found: Answer },
+}
+
+impl Check {
+    pub fn new(found: Answer, expected: Option<&str>) -> Self {
+        match expected {
+            None => Check::Missing { found },
+            Some(expected) if found.to_string() == expected => Check::Pass,
+            Some(expected) => Check::Fail { found, expected: expected.to_string() },
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Check::Fail { .. })
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail { .. } => "fail",
+            Check::Missing { .. } => "missing",
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { found, expected } => write!(f, "FAIL (found {found}, expected {expected})"),
+            Check::Missing { found } => write!(f, "missing (found {found})"),
+        }
+    }
+}
+
+/// Answers manifest recorded next to an input, e.g. `input/day07.answers` for `input/day07`.
+pub fn answers_path(input_path: &str) -> String {
+    format!("{input_path}.answers")
+}
+
+/// Reads the recorded answers of both parts, one per line, part 1 first.
+///
+/// A missing manifest or an empty line means that the answer of that part is not known yet.
+pub fn read_answers(path: &str) -> io::Result<[Option<String>; 2]> {
+    let text = match read_file(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok([None, None]),
+        Err(err) => return Err(err),
+    };
+
+    let mut lines = text.lines()
+        .map(str::trim)
+        .map(|line| (!line.is_empty()).then(|| line.to_string()));
+
+    return Ok([lines.next().flatten(), lines.next().flatten()]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_compare_the_printed_answer() {
+        assert_eq!(Check::new(Answer::Int(42), Some("42")), Check::Pass);
+        assert!(Check::new(Answer::Int(42), Some("41")).is_fail());
+        assert_eq!(Check::new(Answer::Int(42), None), Check::Missing { found: Answer::Int(42) });
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::time::{Duration, Instant};
+
+use utils::{Answer, ParseError, Solution};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Wall time statistics of one stage over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>) -> Self {
+        times.sort_unstable();
+
+        Self { min: times[0], median: times[times.len() / 2], max: times[times.len() - 1] }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2?} / {:.2?} / {:.2?}", self.min, self.median, self.max)
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+
+    (result, start.elapsed())
+}
+
+/// Answers of a single run, along with the time taken by each stage.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: [(Answer, Duration); 2],
+}
+
+pub fn run<S: Solution>(input: &str) -> Result<Run, ParseError> {
+    let (parsed, parse_time) = time(|| S::parse(input));
+    let parsed = parsed?;
+
+    return Ok(Run {
+        parse_time,
+        parts: [time(|| S::part1(&parsed)), time(|| S::part2(&parsed))],
+    });
+}
+
+/// Times parsing, part 1 and part 2 separately, each of them run `iterations` times.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<[Stats; 3], ParseError> {
+    assert!(iterations > 0, "At least one iteration is needed");
+    let mut times: [Vec<Duration>; 3] = Default::default();
+
+    for _ in 0..iterations {
+        let (parsed, parse_time) = time(|| S::parse(black_box(input)));
+        let parsed = parsed?;
+        let (_, part1_time) = time(|| S::part1(&parsed));
+        let (_, part2_time) = time(|| S::part2(&parsed));
+
+        for (stage, elapsed) in times.iter_mut().zip([parse_time, part1_time, part2_time]) {
+            stage.push(elapsed);
+        }
+    }
+
+    return Ok(times.map(Stats::new));
+}
+
+/// Writes the results as CSV, one line per day and stage, with times in nanoseconds.
+pub fn write_report(path: &str, iterations: usize, results: &[(usize, [Stats; 3])]) -> io::Result<()> {
+    let mut report = String::from("day,stage,iterations,min_ns,median_ns,max_ns\n");
+
+    for (day, stats) in results {
+        for (stage, stats) in STAGES.iter().zip(stats) {
+            report += &format!(
+                "{day:02},{stage},{iterations},{},{},{}\n",
+                stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()
+            );
+        }
+    }
+
+    return fs::write(path, report);
+}
@@ -0,0 +1,40 @@
+use utils::{ParseError, Solution};
+
+use crate::bench::{bench, run, Run, Stats};
+
+pub type Solver = fn(&str) -> Result<Run, ParseError>;
+pub type Bencher = fn(&str, usize) -> Result<[Stats; 3], ParseError>;
+
+/// Entry points of a day, with its solution type erased.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solve: Solver,
+    pub bench: Bencher,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day { solve: run::<S>, bench: bench::<S> }
+}
+
+pub const DAYS: [Day; 16] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+];
+
+pub fn get_day(day: usize) -> Option<Day> {
+    day.checked_sub(1).and_then(|i| DAYS.get(i)).copied()
+}
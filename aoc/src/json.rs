@@ -0,0 +1,93 @@
+use std::fmt;
+
+use utils::Answer;
+
+/// Flat JSON object, built field by field and printed on a single line.
+#[derive(Debug, Clone, Default)]
+pub struct Object {
+    fields: Vec<(&'static str, String)>,
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if c.is_control() => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    return escaped;
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn number(mut self, key: &'static str, num: impl Into<u128>) -> Self {
+        self.fields.push((key, num.into().to_string()));
+        self
+    }
+
+    pub fn string(mut self, key: &'static str, text: &str) -> Self {
+        self.fields.push((key, escape(text)));
+        self
+    }
+
+    pub fn null(mut self, key: &'static str) -> Self {
+        self.fields.push((key, "null".to_string()));
+        self
+    }
+
+    pub fn optional_string(self, key: &'static str, text: Option<&str>) -> Self {
+        match text {
+            Some(text) => self.string(key, text),
+            None => self.null(key),
+        }
+    }
+
+    /// Integer answers are kept as numbers, any other answer as a string.
+    pub fn answer(mut self, key: &'static str, answer: &Answer) -> Self {
+        let value = match answer {
+            Answer::Int(num) => num.to_string(),
+            Answer::Text(text) => escape(text),
+        };
+
+        self.fields.push((key, value));
+        self
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<String> = self.fields.iter()
+            .map(|(key, value)| format!("{}:{value}", escape(key)))
+            .collect();
+
+        write!(f, "{{{}}}", fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objects_are_escaped() {
+        let object = Object::new()
+            .number("day", 3u8)
+            .answer("answer", &Answer::Int(-4))
+            .string("error", "line 1: \"x\"\n")
+            .null("time_ns");
+
+        assert_eq!(object.to_string(), r#"{"day":3,"answer":-4,"error":"line 1: \"x\"\n","time_ns":null}"#);
+    }
+}
@@ -0,0 +1,253 @@
+use std::env;
+use std::process::ExitCode;
+
+use utils::read_file;
+
+mod answers;
+mod bench;
+mod days;
+mod json;
+mod report;
+
+use answers::{answers_path, read_answers, Check};
+use bench::{write_report, Run, Stats};
+use days::{get_day, Day, DAYS};
+use report::{error_json, Report};
+
+const USAGE: &str = "Usage: aoc <run | verify | bench> <day | first..last | all> [-i <input>] [-n <iterations>] [-o <output>] [-f <table | json>]";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_BENCH_OUTPUT: &str = "bench.csv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Print the answers of both parts
+    Run,
+    /// Compare the answers against the manifest recorded next to the input
+    Verify,
+    /// Time parsing and both parts separately over several iterations
+    Bench,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Human-readable table, with errors on stderr
+    Table,
+    /// One JSON object per line and part, errors included
+    Json,
+}
+
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    days: Vec<usize>,
+    input: Option<String>,
+    iterations: usize,
+    output: String,
+    format: Format,
+}
+
+fn parse_day(word: &str) -> Result<usize, String> {
+    let day = word.parse().map_err(|_| format!("Invalid day: {word}"))?;
+
+    if get_day(day).is_none() {
+        return Err(format!("Day {day} is not solved (available: 1..{})", DAYS.len()));
+    }
+
+    return Ok(day);
+}
+
+fn parse_days(word: &str) -> Result<Vec<usize>, String> {
+    if word == "all" {
+        return Ok((1..=DAYS.len()).collect());
+    }
+
+    if let Some((first, last)) = word.split_once("..") {
+        let (first, last) = (parse_day(first)?, parse_day(last)?);
+        if first > last {
+            return Err(format!("Empty day range: {word}"));
+        }
+
+        return Ok((first..=last).collect());
+    }
+
+    return Ok(vec![parse_day(word)?]);
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("bench") => Command::Bench,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let days = parse_days(&args.next().ok_or(USAGE)?)?;
+    let mut input = None;
+    let mut iterations = None;
+    let mut output = None;
+    let mut format = Format::Table;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--input" => input = Some(args.next().ok_or(USAGE)?),
+            "-n" | "--iterations" => {
+                let word = args.next().ok_or(USAGE)?;
+                match word.parse() {
+                    Ok(n) if n > 0 => iterations = Some(n),
+                    _ => return Err(format!("Invalid number of iterations: {word}")),
+                }
+            },
+            "-o" | "--output" => output = Some(args.next().ok_or(USAGE)?),
+            "-f" | "--format" => {
+                format = match args.next().ok_or(USAGE)?.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    word => return Err(format!("Invalid format: {word}")),
+                };
+            },
+            _ => return Err(format!("Unexpected argument: {arg}\n{USAGE}")),
+        }
+    }
+
+    if input.is_some() && days.len() > 1 {
+        return Err("A custom input can only be used when running a single day".to_string());
+    }
+
+    if command != Command::Bench && (iterations.is_some() || output.is_some()) {
+        return Err("Iterations and output file can only be used when benchmarking".to_string());
+    }
+
+    return Ok(Args {
+        command,
+        days,
+        input,
+        iterations: iterations.unwrap_or(DEFAULT_ITERATIONS),
+        output: output.unwrap_or_else(|| DEFAULT_BENCH_OUTPUT.to_string()),
+        format,
+    });
+}
+
+fn default_input(day: usize) -> String {
+    format!("input/day{day:02}")
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|title| title.len()).collect();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    // First column is right-aligned and the last one is not padded
+    let last = widths.len() - 1;
+    let print_row = |row: &[&str]| {
+        let cells: Vec<String> = row.iter().enumerate()
+            .map(|(i, cell)| match i {
+                0 => format!("{cell:>w$}", w = widths[0]),
+                i if i == last => cell.to_string(),
+                i => format!("{cell:<w$}", w = widths[i]),
+            })
+            .collect();
+
+        println!("{}", cells.join(" | "));
+    };
+
+    print_row(header);
+    println!("{}", widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<_>>().join("-+-"));
+    rows.iter().for_each(|row| print_row(&row.iter().map(String::as_str).collect::<Vec<_>>()));
+}
+
+fn solve_day(day: Day, input: &str, path: &str) -> Result<Run, String> {
+    (day.solve)(input).map_err(|err| format!("failed to parse {path}: {err}"))
+}
+
+fn verify_day(day: Day, input: &str, path: &str) -> Result<(Run, [Check; 2]), String> {
+    let run = solve_day(day, input, path)?;
+
+    let manifest = answers_path(path);
+    let expected = read_answers(&manifest)
+        .map_err(|err| format!("failed to read {manifest}: {err}"))?;
+
+    let checks = [0, 1].map(|i| Check::new(run.parts[i].0.clone(), expected[i].as_deref()));
+    return Ok((run, checks));
+}
+
+fn bench_day(day: Day, input: &str, path: &str, iterations: usize) -> Result<[Stats; 3], String> {
+    (day.bench)(input, iterations).map_err(|err| format!("failed to parse {path}: {err}"))
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut rows = vec![];
+    let mut bench_results = vec![];
+    let mut failed = false;
+
+    for &num in &args.days {
+        let day = get_day(num).expect("Days are validated when parsing arguments");
+        let path = args.input.clone().unwrap_or_else(|| default_input(num));
+
+        let report = read_file(&path)
+            .map_err(|err| format!("failed to read {path}: {err}"))
+            .and_then(|input| match args.command {
+                Command::Run => solve_day(day, &input, &path).map(Report::Run),
+                Command::Verify => verify_day(day, &input, &path).map(|(run, checks)| Report::Verify(run, checks)),
+                Command::Bench => bench_day(day, &input, &path, args.iterations).map(Report::Bench),
+            });
+
+        let report = match report {
+            Ok(report) => report,
+            Err(msg) => {
+                match args.format {
+                    Format::Table => eprintln!("Day {num:02}: {msg}"),
+                    Format::Json => error_json(num, &msg).iter().for_each(|object| println!("{object}")),
+                }
+                failed = true;
+                continue;
+            }
+        };
+
+        match &report {
+            Report::Verify(_, checks) => failed |= checks.iter().any(Check::is_fail),
+            Report::Bench(stats) => bench_results.push((num, *stats)),
+            Report::Run(_) => {},
+        }
+
+        match args.format {
+            Format::Table => rows.push([vec![format!("{num:02}")], report.cells()].concat()),
+            Format::Json => report.to_json(num).iter().for_each(|object| println!("{object}")),
+        }
+    }
+
+    if !rows.is_empty() {
+        match args.command {
+            Command::Run | Command::Verify => print_table(&["Day", "Part 1", "Part 2"], &rows),
+            Command::Bench => {
+                print_table(&["Day", "Parse", "Part 1", "Part 2"], &rows);
+                println!("\nTimes are min / median / max over {} iterations", args.iterations);
+            },
+        }
+    }
+
+    if !bench_results.is_empty() {
+        match write_report(&args.output, args.iterations, &bench_results) {
+            Ok(()) if args.format == Format::Table => println!("Results stored in {}", args.output),
+            Ok(()) => {},
+            Err(err) => {
+                eprintln!("Failed to write {}: {err}", args.output);
+                failed = true;
+            }
+        }
+    }
+
+    return if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+}
@@ -0,0 +1,83 @@
+use crate::answers::Check;
+use crate::bench::{Run, Stats, STAGES};
+use crate::json::Object;
+
+/// Outcome of running a command on a single day.
+#[derive(Debug, Clone)]
+pub enum Report {
+    Run(Run),
+    Verify(Run, [Check; 2]),
+    Bench([Stats; 3]),
+}
+
+impl Report {
+    /// Cells of the day in the printed table, after the day number.
+    pub fn cells(&self) -> Vec<String> {
+        match self {
+            Report::Run(run) => run.parts.iter().map(|(answer, _)| answer.to_string()).collect(),
+            Report::Verify(_, checks) => checks.iter().map(Check::to_string).collect(),
+            Report::Bench(stats) => stats.iter().map(Stats::to_string).collect(),
+        }
+    }
+
+    /// One JSON object per part, or per stage when benchmarking.
+    pub fn to_json(&self, day: usize) -> Vec<Object> {
+        let base = |part: usize| Object::new().number("day", day as u64).number("part", part as u64);
+
+        match self {
+            Report::Run(run) => (1..=2)
+                .map(|part| part_json(base(part), run, part))
+                .collect(),
+            Report::Verify(run, checks) => (1..=2)
+                .zip(checks)
+                .map(|(part, check)| {
+                    let expected = match check {
+                        Check::Pass => Some(run.parts[part - 1].0.to_string()),
+                        Check::Fail { expected, .. } => Some(expected.clone()),
+                        Check::Missing { .. } => None,
+                    };
+
+                    part_json(base(part), run, part)
+                        .string("check", check.status())
+                        .optional_string("expected", expected.as_deref())
+                })
+                .collect(),
+            Report::Bench(stats) => STAGES.iter()
+                .zip(stats)
+                .map(|(stage, stats)| {
+                    Object::new()
+                        .number("day", day as u64)
+                        .string("stage", stage)
+                        .number("min_ns", stats.min.as_nanos())
+                        .number("median_ns", stats.median.as_nanos())
+                        .number("max_ns", stats.max.as_nanos())
+                })
+                .collect(),
+        }
+    }
+}
+
+fn part_json(object: Object, run: &Run, part: usize) -> Object {
+    let (answer, time) = &run.parts[part - 1];
+
+    return object
+        .answer("answer", answer)
+        .number("parse_ns", run.parse_time.as_nanos())
+        .number("time_ns", time.as_nanos())
+        .null("error");
+}
+
+/// JSON objects reporting that a day could not be run, one per part.
+pub fn error_json(day: usize, msg: &str) -> Vec<Object> {
+    return (1..=2u64)
+        .map(|part| {
+            Object::new()
+                .number("day", day as u64)
+                .number("part", part)
+                .null("answer")
+                .null("parse_ns")
+                .null("time_ns")
+                .string("error", msg)
+        })
+        .collect();
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use utils::{read_two_lists, Answer, ParseError, Solution};
+
+fn distance(mut list1: Vec<i32>, mut list2: Vec<i32>) -> i32 {
+  list1.sort();
+  list2.sort();
+
+  let distance = list1
+    .into_iter()
+    .zip(list2)
+    .map(|(num1, num2)| (num1 - num2).abs())
+    .sum();
+
+  return distance;
+}
+
+fn count(vec: Vec<i32>) -> HashMap<i32, i32> {
+  let mut res = HashMap::new();
+
+  for elem in vec {
+    *res.entry(elem).or_insert(0) += 1;
+  }
+
+  return res;
+}
+
+fn similarity_score(list1: Vec<i32>, list2: Vec<i32>) -> i32 {
+  let list2_count = count(list2);
+
+  let score = list1
+    .into_iter()
+    .map(|num| num * list2_count.get(&num).unwrap_or(&0))
+    .sum();
+
+  return score;
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Input = (Vec<i32>, Vec<i32>);
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    read_two_lists(input)
+  }
+
+  fn part1((list1, list2): &Self::Input) -> Answer {
+    distance(list1.clone(), list2.clone()).into()
+  }
+
+  fn part2((list1, list2): &Self::Input) -> Answer {
+    similarity_score(list1.clone(), list2.clone()).into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use utils::solution::solve;
+
+  const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+  #[test]
+  fn example() {
+    assert_eq!(solve::<Day01>(EXAMPLE), Ok((Answer::Int(11), Answer::Int(31))));
+  }
+}
@@ -0,0 +1,97 @@
+use utils::{read_int_lists, Answer, ParseError, Solution};
+
+fn tail_skip(vec: &[i32], index: usize) -> Vec<i32> {
+  let mut tail = vec![];
+  tail.extend_from_slice(&vec[(index as i32 - 2).max(0) as usize..index]);
+  tail.extend_from_slice(&vec[index + 1..]);
+
+  return tail;
+}
+
+fn is_safe(report: &[i32], tolerance: bool) -> bool {
+  fn safe_aux(report: &[i32], index: usize, tolerance: bool) -> bool {
+    if !tolerance {
+      return false;
+    }
+
+    for i in index.max(1) - 1..index + 2 {
+      if is_safe(&tail_skip(report, i), false) {
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  let increasing = report[1] > report[0];
+
+  for i in 0..report.len() - 1 {
+    let level_diff = (report[i + 1] - report[i]).abs();
+
+    if (increasing && report[i + 1] <= report[i]) || (!increasing && report[i + 1] >= report[i]) {
+      return safe_aux(report, i, tolerance);
+    }
+
+    if level_diff > 3 {
+      return safe_aux(report, i, tolerance);
+    }
+  }
+
+  return true;
+}
+
+fn safe_count(reports: &[Vec<i32>], tolerance: bool) -> usize {
+  reports.iter().filter(|report| is_safe(report, tolerance)).count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+  type Input = Vec<Vec<i32>>;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    read_int_lists(input)
+  }
+
+  fn part1(reports: &Self::Input) -> Answer {
+    safe_count(reports, false).into()
+  }
+
+  fn part2(reports: &Self::Input) -> Answer {
+    safe_count(reports, true).into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use utils::solution::solve;
+
+  const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+  #[test]
+  fn example() {
+    assert_eq!(solve::<Day02>(EXAMPLE), Ok((Answer::Int(2), Answer::Int(4))));
+  }
+
+  #[test]
+  fn tail_skip_keeps_two_previous_levels() {
+    assert_eq!(tail_skip(&[1, 2, 3, 4, 5], 0), vec![2, 3, 4, 5]);
+    assert_eq!(tail_skip(&[1, 2, 3, 4, 5], 1), vec![1, 3, 4, 5]);
+    assert_eq!(tail_skip(&[1, 2, 3, 4, 5], 3), vec![2, 3, 5]);
+    assert_eq!(tail_skip(&[1, 2, 3, 4, 5], 4), vec![3, 4]);
+  }
+
+  #[test]
+  fn first_level_can_be_removed() {
+    assert!(!is_safe(&[9, 1, 2, 3], false));
+    assert!(is_safe(&[9, 1, 2, 3], true));
+    assert!(is_safe(&[1, 9, 2, 3], true));
+  }
+}
@@ -0,0 +1,618 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::mem;
+use std::ops::Range;
+
+use utils::{Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionKind {
+  Mul,
+  Do,
+  Dont,
+}
+
+/// Spelling of an instruction, written as `name(arg1,arg2,...)` in memory.
+struct Syntax {
+  name: &'static str,
+  arity: usize,
+  kind: InstructionKind,
+}
+
+/// Every instruction the lexer recognises, arguments being non-negative integers written with
+/// any number of digits.
+static INSTRUCTIONS: [Syntax; 3] = [
+  Syntax { name: "mul", arity: 2, kind: InstructionKind::Mul },
+  Syntax { name: "do", arity: 0, kind: InstructionKind::Do },
+  Syntax { name: "don't", arity: 0, kind: InstructionKind::Dont },
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+  pub kind: InstructionKind,
+  pub args: Vec<u128>,
+  /// Byte range of the instruction in memory
+  pub span: Range<usize>,
+}
+
+/// Instruction whose argument, or whose product added to the result, does not fit in 128 bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+  pub span: Range<usize>,
+}
+
+impl fmt::Display for Overflow {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "instruction at bytes {}..{} overflows a 128-bit integer", self.span.start, self.span.end)
+  }
+}
+
+impl Error for Overflow {}
+
+impl From<Overflow> for io::Error {
+  fn from(overflow: Overflow) -> Self {
+    io::Error::new(io::ErrorKind::InvalidData, overflow)
+  }
+}
+
+/// Bytes read from memory at once when streaming it.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Piece of an instruction's syntax.
+enum Part {
+  Literal(&'static [u8]),
+  Number,
+}
+
+impl Syntax {
+  /// `i`-th part of the instruction, from its name to its closing parenthesis.
+  fn part(&self, i: usize) -> Option<Part> {
+    match i {
+      0 => Some(Part::Literal(self.name.as_bytes())),
+      1 if self.arity == 0 => Some(Part::Literal(b"()")),
+      _ if self.arity == 0 => None,
+      1 => Some(Part::Literal(b"(")),
+      _ if i == 2 * self.arity + 1 => Some(Part::Literal(b")")),
+      _ if i > 2 * self.arity => None,
+      _ if i.is_multiple_of(2) => Some(Part::Number),
+      _ => Some(Part::Literal(b",")),
+    }
+  }
+}
+
+/// Instruction matched up to some byte of memory, which may be carried from one chunk to the next.
+#[derive(Clone)]
+struct Candidate {
+  syntax: &'static Syntax,
+  part: usize,
+  /// Bytes of the current literal matched, or digits of the current number read
+  matched: usize,
+  number: Option<u128>,
+  /// Arguments read, `None` once one does not fit in 128 bits
+  args: Option<Vec<u128>>,
+}
+
+enum Progress {
+  Pending,
+  /// The instruction ends with the byte
+  Matched,
+  Rejected,
+}
+
+impl Candidate {
+  fn new(syntax: &'static Syntax) -> Self {
+    Self { syntax, part: 0, matched: 0, number: Some(0), args: Some(vec![]) }
+  }
+
+  fn feed(&mut self, byte: u8) -> Progress {
+    loop {
+      match self.syntax.part(self.part) {
+        Some(Part::Literal(literal)) if literal[self.matched] == byte => {
+          self.matched += 1;
+
+          if self.matched == literal.len() {
+            self.part += 1;
+            self.matched = 0;
+
+            if self.syntax.part(self.part).is_none() {
+              return Progress::Matched;
+            }
+          }
+          return Progress::Pending;
+        },
+        Some(Part::Number) if byte.is_ascii_digit() => {
+          self.matched += 1;
+          self.number = self.number.and_then(|num| num.checked_mul(10)?.checked_add((byte - b'0') as u128));
+
+          return Progress::Pending;
+        },
+        // The number is over, so the byte goes on with the next part
+        Some(Part::Number) if self.matched > 0 => {
+          self.args = self.args.take().zip(self.number).map(|(mut args, arg)| {
+            args.push(arg);
+            args
+          });
+          (self.part, self.matched, self.number) = (self.part + 1, 0, Some(0));
+        },
+        _ => return Progress::Rejected,
+      }
+    }
+  }
+}
+
+/// Scans corrupted memory fed a byte at a time for well-formed instructions, skipping anything
+/// else. No instruction is the start of another, so the first one matched is emitted.
+#[derive(Default)]
+struct Scanner {
+  /// Position in memory of the next byte
+  pos: usize,
+  /// Start of the instruction being matched
+  start: usize,
+  /// Syntaxes still matching the bytes since `start`
+  candidates: Vec<Candidate>,
+  /// Bytes since `start`, to scan again from the one after it if no syntax matches. Digits
+  /// are kept once per run, as no instruction starts with one, so that long numbers take no room.
+  replay: Vec<(usize, u8)>,
+}
+
+impl Scanner {
+  fn feed(&mut self, byte: u8, emit: &mut impl FnMut(Result<Instruction, Overflow>)) {
+    self.feed_at(self.pos, byte, emit);
+    self.pos += 1;
+  }
+
+  fn feed_at(&mut self, pos: usize, byte: u8, emit: &mut impl FnMut(Result<Instruction, Overflow>)) {
+    if self.candidates.is_empty() {
+      // Most bytes start no instruction at all
+      if !INSTRUCTIONS.iter().any(|syntax| syntax.name.as_bytes()[0] == byte) {
+        return;
+      }
+
+      self.start = pos;
+      self.candidates.extend(INSTRUCTIONS.iter().map(Candidate::new));
+      self.replay.clear();
+    }
+    if !byte.is_ascii_digit() || !self.replay.last().is_some_and(|(_, last)| last.is_ascii_digit()) {
+      self.replay.push((pos, byte));
+    }
+
+    let mut matched = None;
+    self.candidates.retain_mut(|candidate| match candidate.feed(byte) {
+      Progress::Pending => true,
+      Progress::Matched => {
+        matched.get_or_insert((candidate.syntax.kind, candidate.args.take()));
+        false
+      },
+      Progress::Rejected => false,
+    });
+
+    if let Some((kind, args)) = matched {
+      let span = self.start..pos + 1;
+      self.candidates.clear();
+
+      emit(match args {
+        Some(args) => Ok(Instruction { kind, args, span }),
+        None => Err(Overflow { span }),
+      });
+    } else if self.candidates.is_empty() {
+      self.rescan(emit);
+    }
+  }
+
+  /// Scans again the bytes after the start of an instruction that was not matched.
+  fn rescan(&mut self, emit: &mut impl FnMut(Result<Instruction, Overflow>)) {
+    let replay = mem::take(&mut self.replay);
+
+    for &(pos, byte) in &replay[1..] {
+      self.feed_at(pos, byte, emit);
+    }
+  }
+
+  /// Drops the instruction left unfinished at the end of memory.
+  fn finish(&mut self, emit: &mut impl FnMut(Result<Instruction, Overflow>)) {
+    while !self.candidates.is_empty() {
+      self.candidates.clear();
+      self.rescan(emit);
+    }
+  }
+}
+
+/// Lexer streaming memory from a reader in chunks, only carrying the instruction being matched
+/// from one chunk to the next.
+pub struct ReaderLexer<R> {
+  reader: R,
+  scanner: Scanner,
+  found: VecDeque<Result<Instruction, Overflow>>,
+  done: bool,
+}
+
+impl<R: Read> ReaderLexer<R> {
+  pub fn new(reader: R) -> Self {
+    Self { reader, scanner: Scanner::default(), found: VecDeque::new(), done: false }
+  }
+
+  fn scan_next_chunk(&mut self) -> io::Result<()> {
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let read = loop {
+      match self.reader.read(&mut chunk) {
+        Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+        result => break result?,
+      }
+    };
+
+    let mut emit = |instruction| self.found.push_back(instruction);
+    for &byte in &chunk[..read] {
+      self.scanner.feed(byte, &mut emit);
+    }
+
+    self.done = read == 0;
+    if self.done {
+      self.scanner.finish(&mut emit);
+    }
+
+    return Ok(());
+  }
+}
+
+impl<R: Read> Iterator for ReaderLexer<R> {
+  type Item = io::Result<Instruction>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(found) = self.found.pop_front() {
+        return Some(found.map_err(io::Error::from));
+      }
+      if self.done {
+        return None;
+      }
+      if let Err(err) = self.scan_next_chunk() {
+        self.done = true;
+        return Some(Err(err));
+      }
+    }
+  }
+}
+
+impl fmt::Display for Instruction {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let syntax = INSTRUCTIONS.iter()
+      .find(|syntax| syntax.kind == self.kind)
+      .expect("Every instruction kind has a syntax");
+    let args: Vec<String> = self.args.iter().map(u128::to_string).collect();
+
+    write!(f, "{}({})", syntax.name, args.join(","))
+  }
+}
+
+pub fn lex(memory: &str) -> Result<Vec<Instruction>, Overflow> {
+  let mut instructions = vec![];
+  let mut emit = |instruction| instructions.push(instruction);
+  let mut scanner = Scanner::default();
+
+  for &byte in memory.as_bytes() {
+    scanner.feed(byte, &mut emit);
+  }
+  scanner.finish(&mut emit);
+
+  return instructions.into_iter().collect();
+}
+
+/// What an instruction did when it was interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+  Counted,
+  Skipped,
+  Enables,
+  Disables,
+  Ignored,
+}
+
+impl fmt::Display for Effect {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let text = match self {
+      Effect::Counted => "counted",
+      Effect::Skipped => "skipped",
+      Effect::Enables => "enables",
+      Effect::Disables => "disables",
+      Effect::Ignored => "ignored",
+    };
+
+    write!(f, "{text}")
+  }
+}
+
+/// Instruction along with the state of the interpreter when it was reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+  pub instruction: Instruction,
+  /// Whether `mul` instructions were enabled at this point
+  pub enabled: bool,
+  /// Whether `do()` and `don't()` are taken into account
+  pub conditional: bool,
+}
+
+impl Step {
+  /// Value added to the result by this instruction, if any and if it fits in 128 bits.
+  pub fn product(&self) -> Option<u128> {
+    match self.instruction.kind {
+      InstructionKind::Mul if self.enabled => self.instruction.args[0].checked_mul(self.instruction.args[1]),
+      _ => None,
+    }
+  }
+
+  pub fn effect(&self) -> Effect {
+    match self.instruction.kind {
+      InstructionKind::Mul if self.enabled => Effect::Counted,
+      InstructionKind::Mul => Effect::Skipped,
+      _ if !self.conditional => Effect::Ignored,
+      InstructionKind::Do => Effect::Enables,
+      InstructionKind::Dont => Effect::Disables,
+    }
+  }
+}
+
+/// Applies the enable/disable semantics to the instructions, `do()` and `don't()` toggling
+/// `mul` instructions only when `conditional` is set.
+pub fn explain(instructions: impl IntoIterator<Item = Instruction>, conditional: bool) -> impl Iterator<Item = Step> {
+  let mut enabled = true;
+
+  instructions.into_iter().map(move |instruction| {
+    let step = Step { enabled, conditional, instruction };
+
+    match step.instruction.kind {
+      InstructionKind::Do if conditional => enabled = true,
+      InstructionKind::Dont if conditional => enabled = false,
+      _ => {},
+    }
+
+    step
+  })
+}
+
+fn add_product(total: u128, step: &Step) -> Result<u128, Overflow> {
+  if step.effect() != Effect::Counted {
+    return Ok(total);
+  }
+
+  return step.product()
+    .and_then(|product| total.checked_add(product))
+    .ok_or_else(|| Overflow { span: step.instruction.span.clone() });
+}
+
+fn interpret(instructions: &[Instruction], conditional: bool) -> Result<u128, Overflow> {
+  explain(instructions.iter().cloned(), conditional)
+    .try_fold(0, |total, step| add_product(total, &step))
+}
+
+/// Streams memory from a reader, calling `visit` on every instruction found, and returns the
+/// sum of the products.
+pub fn explain_reader(reader: impl Read, conditional: bool, mut visit: impl FnMut(&Step)) -> io::Result<u128> {
+  let mut error = None;
+  let mut total = 0;
+
+  let instructions = ReaderLexer::new(reader)
+    .map_while(|result| result.map_err(|err| error = Some(err)).ok());
+
+  for step in explain(instructions, conditional) {
+    visit(&step);
+    total = add_product(total, &step)?;
+  }
+
+  return match error {
+    Some(err) => Err(err),
+    None => Ok(total),
+  };
+}
+
+pub fn eval_reader(reader: impl Read, conditional: bool) -> io::Result<u128> {
+  explain_reader(reader, conditional, |_| {})
+}
+
+/// Describes an instruction with its byte span, operands and effect on the result.
+pub fn describe(step: &Step) -> String {
+  let span = format!("{}..{}", step.instruction.span.start, step.instruction.span.end);
+  let product = step.product().map(|product| format!("= {product}")).unwrap_or_default();
+
+  format!("{span:<16} {:<16} {product:<10} {}", step.instruction.to_string(), step.effect())
+}
+
+const GREEN: &str = "\x1b[1;32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Prints the memory with counted instructions in green, skipped ones in red and the
+/// instructions toggling them in yellow.
+pub fn highlight(memory: &str, conditional: bool) -> Result<String, Overflow> {
+  let mut highlighted = String::new();
+  let mut last = 0;
+
+  for step in explain(lex(memory)?, conditional) {
+    let colour = match step.effect() {
+      Effect::Counted => GREEN,
+      Effect::Skipped => RED,
+      Effect::Enables | Effect::Disables => YELLOW,
+      Effect::Ignored => continue,
+    };
+
+    let span = step.instruction.span;
+    highlighted += &memory[last..span.start];
+    highlighted += colour;
+    highlighted += &memory[span.clone()];
+    highlighted += RESET;
+    last = span.end;
+  }
+
+  highlighted += &memory[last..];
+  return Ok(highlighted);
+}
+
+/// Sums the products of every `mul` instruction in memory.
+pub fn eval(string: &str) -> Result<u128, Overflow> {
+  interpret(&lex(string)?, false)
+}
+
+/// Sums the products of the `mul` instructions in memory that are enabled by `do()` and `don't()`.
+pub fn eval_cond(string: &str) -> Result<u128, Overflow> {
+  interpret(&lex(string)?, true)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+  type Input = Vec<Instruction>;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    // Products are never negative, so the enabled ones cannot overflow if all of them fit
+    let overflow = |Overflow { span }: Overflow| {
+      let line = input[..span.start].matches('\n').count() + 1;
+      ParseError::invalid(line, &input[span], "result overflows a 128-bit integer".to_string())
+    };
+
+    let instructions = lex(input).map_err(overflow)?;
+    interpret(&instructions, false).map_err(overflow)?;
+
+    return Ok(instructions);
+  }
+
+  fn part1(instructions: &Self::Input) -> Answer {
+    interpret(instructions, false).expect("Overflow is checked when parsing").into()
+  }
+
+  fn part2(instructions: &Self::Input) -> Answer {
+    interpret(instructions, true).expect("Overflow is checked when parsing").into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use utils::solution::solve;
+
+  const EXAMPLE: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+  #[test]
+  fn example() {
+    assert_eq!(solve::<Day03>(EXAMPLE), Ok((Answer::Int(161), Answer::Int(48))));
+  }
+
+  #[test]
+  fn lexer_reports_spans() {
+    let instructions = lex("xdon't()mul(2,4)do(").unwrap();
+
+    assert_eq!(instructions, vec![
+      Instruction { kind: InstructionKind::Dont, args: vec![], span: 1..8 },
+      Instruction { kind: InstructionKind::Mul, args: vec![2, 4], span: 8..16 },
+    ]);
+  }
+
+  #[test]
+  fn explain_tells_why_muls_are_skipped() {
+    let effects: Vec<_> = explain(lex(EXAMPLE).unwrap(), true)
+      .map(|step| (step.instruction.to_string(), step.effect()))
+      .collect();
+
+    assert_eq!(effects, vec![
+      ("mul(2,4)".to_string(), Effect::Counted),
+      ("don't()".to_string(), Effect::Disables),
+      ("mul(5,5)".to_string(), Effect::Skipped),
+      ("mul(11,8)".to_string(), Effect::Skipped),
+      ("do()".to_string(), Effect::Enables),
+      ("mul(8,5)".to_string(), Effect::Counted),
+    ]);
+  }
+
+  #[test]
+  fn highlight_keeps_the_memory() {
+    let highlighted = highlight("amul(2,3)b", false);
+
+    assert_eq!(highlighted, Ok(format!("a{GREEN}mul(2,3){RESET}b")));
+  }
+
+  /// Reader handing out a single byte at a time, to split every instruction between chunks.
+  struct Trickle<'a>(&'a [u8]);
+
+  impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+      let Some((&byte, rest)) = self.0.split_first() else { return Ok(0) };
+      buf[0] = byte;
+      self.0 = rest;
+
+      Ok(1)
+    }
+  }
+
+  #[test]
+  fn streaming_carries_instructions_across_chunks() {
+    let instructions: Vec<_> = ReaderLexer::new(Trickle(EXAMPLE.as_bytes())).map(Result::unwrap).collect();
+
+    assert_eq!(instructions, lex(EXAMPLE).unwrap());
+  }
+
+  #[test]
+  fn streaming_matches_whole_memory() {
+    let memory = EXAMPLE.repeat(CHUNK_SIZE / EXAMPLE.len() + 2);
+
+    assert_eq!(ReaderLexer::new(memory.as_bytes()).map(Result::unwrap).collect::<Vec<_>>(), lex(&memory).unwrap());
+    assert_eq!(eval_reader(memory.as_bytes(), true).unwrap(), eval_cond(&memory).unwrap());
+  }
+
+  #[test]
+  fn streaming_only_carries_the_instruction_being_matched() {
+    let memory = format!("xmul({}7,6)mul(2,3)", "0".repeat(3 * CHUNK_SIZE));
+    let mut lexer = ReaderLexer::new(memory.as_bytes());
+
+    lexer.scan_next_chunk().unwrap();
+    lexer.scan_next_chunk().unwrap();
+    assert!(lexer.found.is_empty());
+    assert_eq!(lexer.scanner.replay.len(), 5);
+
+    let args: Vec<_> = lexer.map(|instruction| instruction.unwrap().args).collect();
+    assert_eq!(args, vec![vec![7, 6], vec![2, 3]]);
+  }
+
+  #[test]
+  fn long_numbers_are_accepted() {
+    assert_eq!(eval("mul(1234567890,2)mul(2,3)"), Ok(2469135786));
+    assert_eq!(eval(&"mul(999999999,999999999)".repeat(10)), Ok(9999999980000000010));
+    assert_eq!(eval("mul(0000000000000000000000000000000000000000012,2)"), Ok(24));
+  }
+
+  #[test]
+  fn overflows_are_reported() {
+    let huge = u128::MAX.to_string();
+
+    assert_eq!(eval(&format!("mul(2,3)mul({huge}0,1)")), Err(Overflow { span: 8..55 }));
+    assert_eq!(eval(&format!("mul({huge},1)mul(1,1)")), Err(Overflow { span: 46..54 }));
+    assert_eq!(eval_cond(&format!("don't()mul({huge},2)do()mul(1,1)")), Ok(1));
+    assert_eq!(eval_reader(Trickle(format!("mul({huge}0,1)").as_bytes()), false).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+    let err = Day03::parse(&format!("mul(2,3)\nmul({huge},2)")).unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.to_string(), "line 2: result overflows a 128-bit integer");
+  }
+
+  #[test]
+  fn eval_only_accepts_well_formed_muls() {
+    assert_eq!(eval("mul(44,46)"), Ok(2024));
+    assert_eq!(eval("mmul(2,3)"), Ok(6));
+    assert_eq!(eval("mul(4*"), Ok(0));
+    assert_eq!(eval("mul(6,9!"), Ok(0));
+    assert_eq!(eval("?(12,34)"), Ok(0));
+    assert_eq!(eval("mul ( 2 , 4 )"), Ok(0));
+    assert_eq!(eval("don't()mul(2,3)"), Ok(6));
+  }
+
+  #[test]
+  fn eval_cond_toggles_on_do_and_dont() {
+    assert_eq!(eval_cond("mul(2,3)don't()mul(4,5)"), Ok(6));
+    assert_eq!(eval_cond("don't()mul(2,3)do()mul(4,5)"), Ok(20));
+    assert_eq!(eval_cond("don't()mul(2,3)dddo()mul(4,5)"), Ok(20));
+    assert_eq!(eval_cond("don't()do_mul(2,3)"), Ok(0));
+    assert_eq!(eval_cond("don'tmul(2,3)"), Ok(6));
+    assert_eq!(eval_cond("dmul(2,3)"), Ok(6));
+  }
+}
@@ -1,219 +1,58 @@
-use utils::read_all;
-
-#[derive(Clone, Copy, Debug)]
-enum State {
-  Start,
-  M,
-  U,
-  L,
-  MulLeftBracket,
-  XValue { x: i32 },
-  Comma { x: i32 },
-  YValue { x: i32, y: i32 },
-  End { x: i32, y: i32 },
-  D { ignore: bool },
-  O { ignore: bool },
-  N,
-  SingleQuote,
-  T,
-  DoLeftBracket { ignore: bool },
-  DontLeftBracket,
-  Ignore,
-}
-
-impl State {
-  fn next_state1(&self, c: u8) -> Self {
-    match *self {
-      State::Start => match c {
-        b'm' => State::M,
-        _ => State::Start
-      },
-      State::M => match c {
-        b'u' => State::U,
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::U => match c {
-        b'l' => State::L,
-        b'm' => State::M,
-        _ => State::Start
-      },
-      State::L => match c {
-        b'(' => State::MulLeftBracket,
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::MulLeftBracket => match c {
-        b'0'..=b'9' => State::XValue { x: (c - b'0') as i32 },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::XValue { x } => match c {
-        b'0'..=b'9' => State::XValue { x: x * 10 + (c - b'0') as i32 },
-        b',' => State::Comma { x },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::Comma { x } => match c {
-        b'0'..=b'9' => State::YValue { x, y: (c - b'0') as i32 },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::YValue { x, y } => match c {
-        b'0'..=b'9' => State::YValue { x, y: y * 10 + (c - b'0') as i32 },
-        b')' => State::End { x, y },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::End { x: _, y: _ } => *self,
-      _ => panic!("Invalid state"),
-    }
-  }
-
-  fn next_state2(&self, c: u8) -> Self {
-    match *self {
-      State::Start => match c {
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start
-      },
-      State::M => match c {
-        b'u' => State::U,
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::U => match c {
-        b'l' => State::L,
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start
-      },
-      State::L => match c {
-        b'(' => State::MulLeftBracket,
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::MulLeftBracket => match c {
-        b'0'..=b'9' => State::XValue { x: (c - b'0') as i32 },
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::XValue { x } => match c {
-        b'0'..=b'9' => State::XValue { x: x * 10 + (c - b'0') as i32 },
-        b',' => State::Comma { x },
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::Comma { x } => match c {
-        b'0'..=b'9' => State::YValue { x, y: (c - b'0') as i32 },
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::YValue { x, y } => match c {
-        b'0'..=b'9' => State::YValue { x, y: y * 10 + (c - b'0') as i32 },
-        b')' => State::End { x, y },
-        b'm' => State::M,
-        b'd' => State::D { ignore: false },
-        _ => State::Start,
-      },
-      State::End { x: _, y: _ } => *self,
-      State::D { ignore } => match c {
-        b'o' => State::O { ignore },
-        b'd' => State::D { ignore },
-        _ if ignore => State::Ignore,
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::O { ignore } => match c {
-        b'(' => State::DoLeftBracket { ignore },
-        b'd' => State::D { ignore },
-        _ if ignore => State::Ignore,
-        b'n' => State::N,
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::N => match c {
-        b'\'' => State::SingleQuote,
-        b'd' => State::D { ignore: false },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::SingleQuote => match c {
-        b't' => State::T,
-        b'd' => State::D { ignore: false },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::T => match c {
-        b'(' => State::DontLeftBracket,
-        b'd' => State::D { ignore: false },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::DoLeftBracket { ignore } => match c {
-        b')' => State::Start,
-        b'd' => State::D { ignore },
-        _ if ignore => State::Ignore,
-        b'm' => State::M,
-        _ => State::Start,
-      }
-      State::DontLeftBracket => match c {
-        b')' => State::Ignore,
-        b'd' => State::D { ignore: false },
-        b'm' => State::M,
-        _ => State::Start,
-      },
-      State::Ignore => match c {
-        b'd' => State::D { ignore: true },
-        _ => State::Ignore,
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use day03::{describe, eval, eval_cond, explain_reader, highlight};
+use utils::read_input;
+
+const USAGE: &str = "Usage: day03 [-c | --conditional] [-q | --quiet] [--highlight] [input]";
+
+fn main() -> ExitCode {
+  let (mut conditional, mut quiet, mut colour, mut path) = (false, false, false, None);
+
+  for arg in env::args().skip(1) {
+    match arg.as_str() {
+      "-c" | "--conditional" => conditional = true,
+      "-q" | "--quiet" => quiet = true,
+      "--highlight" => colour = true,
+      _ if path.is_none() && !arg.starts_with('-') => path = Some(arg),
+      _ => {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
       }
     }
   }
-}
 
-fn eval(string: &str) -> i32 {
-  let mut state = State::Start;
-  let mut res = 0;
-
-  for &c in string.as_bytes() {
-    state = state.next_state1(c);
-    
-    if let State::End { x, y } = state {
-      res += x * y;
-      state = State::Start;
-    }
-  }
-
-  return res;
-}
-
-fn eval_cond(string: &str) -> i32 {
-  let mut state = State::Start;
-  let mut res = 0;
-
-  for &c in string.as_bytes() {
-    state = state.next_state2(c);
-    
-    if let State::End { x, y } = state {
-      res += x * y;
-      state = State::Start;
+  // Input is streamed from the file given as argument, or from stdin otherwise
+  let reader: Box<dyn Read> = match &path {
+    Some(path) => match File::open(path) {
+      Ok(file) => Box::new(file),
+      Err(err) => {
+        eprintln!("Failed to read input: {err}");
+        return ExitCode::FAILURE;
+      }
+    },
+    None => Box::new(io::stdin().lock()),
+  };
+
+  // Highlighting prints the whole memory back, so only then is it loaded at once
+  let result = if colour {
+    read_input(reader).and_then(|memory| {
+      println!("{}", highlight(&memory, conditional)?);
+      Ok(if conditional { eval_cond(&memory)? } else { eval(&memory)? })
+    })
+  } else {
+    explain_reader(reader, conditional, |step| if !quiet { println!("{}", describe(step)) })
+  };
+
+  match result {
+    Ok(total) => println!("Result: {total}"),
+    Err(err) => {
+      eprintln!("Failed to read input: {err}");
+      return ExitCode::FAILURE;
     }
   }
 
-  return res;
-}
-
-fn main() {
-  let input = read_all();
-  
-  let value1 = eval(&input);
-  let value2 = eval_cond(&input);
-
-  println!("Result (part 1): {value1}");
-  println!("Result (part 2): {value2}");
+  return ExitCode::SUCCESS;
 }
@@ -0,0 +1,52 @@
+use utils::search::{find_words, Directions, Stencil, Symmetry};
+use utils::{read_grid, Answer, Grid, ParseError, Solution};
+
+fn count_xmas(grid: &Grid<u8>) -> usize {
+    return find_words(grid, &["XMAS"], Directions::All).len();
+}
+
+fn count_x_mas(grid: &Grid<u8>) -> usize {
+    let x_mas = Stencil::parse("M.S / .A. / M.S").expect("X-MAS stencil is valid");
+    return x_mas.count(grid, &Symmetry::ALL);
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        count_xmas(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        count_x_mas(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day04>(EXAMPLE), Ok((Answer::Int(18), Answer::Int(9))));
+    }
+}
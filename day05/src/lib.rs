@@ -0,0 +1,294 @@
+use std::collections::{HashMap, HashSet};
+use utils::graph::{topological_sort, Cycle};
+use utils::error::parse_token;
+use utils::{Answer, ParseError, Solution};
+
+/// Parses the pages of a line separated by `separator`, along with their columns.
+fn read_pages(line_num: usize, line: &str, separator: char) -> Result<Vec<i32>, ParseError> {
+    let mut column = 1;
+
+    return line.split(separator)
+        .map(|word| {
+            let page = parse_token(line_num, column, word, "a page number");
+            column += word.len() + 1;
+            page
+        })
+        .collect();
+}
+
+fn read_order<'a>(lines: &mut impl Iterator<Item = (usize, &'a str)>) -> Result<Vec<(i32, i32)>, ParseError> {
+    return lines.take_while(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let pages = read_pages(i + 1, line, '|')?;
+
+            match pages[..] {
+                [before, after] => Ok((before, after)),
+                _ => Err(ParseError::wrong_count(i + 1, line, "pages", 2, pages.len())),
+            }
+        })
+        .collect();
+}
+
+fn read_updates<'a>(lines: impl Iterator<Item = (usize, &'a str)>) -> Result<Vec<Vec<i32>>, ParseError> {
+    return lines
+        .map(|(i, line)| read_pages(i + 1, line, ','))
+        .collect();
+}
+
+/// Ordering rules compiled into a set of page pairs, so that checking whether a page must
+/// precede another takes constant time, in memory proportional to the number of rules.
+#[derive(Debug, Clone)]
+pub struct Precedence {
+    rules: HashSet<(i32, i32)>,
+}
+
+impl Precedence {
+    pub fn new(order: &[(i32, i32)]) -> Self {
+        return Self { rules: order.iter().copied().collect() };
+    }
+
+    /// Whether a rule requires `before` to be printed before `after`.
+    pub fn must_precede(&self, before: i32, after: i32) -> bool {
+        return self.rules.contains(&(before, after));
+    }
+}
+
+fn is_ordered(update: &[i32], precedence: &Precedence) -> bool {
+    // No page may come after a page that has to be printed later
+    return update.iter()
+        .enumerate()
+        .all(|(i, &page)| update[..i].iter().all(|&earlier| !precedence.must_precede(page, earlier)));
+}
+
+fn topsort(update: &[i32], precedence: &Precedence) -> Result<Vec<i32>, Cycle<i32>> {
+    return topological_sort(update, |page| {
+        update.iter()
+            .copied()
+            .filter(move |&after| precedence.must_precede(page, after))
+    });
+}
+
+/// Explains which rules make the pages of an update impossible to order.
+fn describe_cycle(Cycle(pages): &Cycle<i32>, order: &[(i32, i32)]) -> String {
+    let rules: Vec<String> = pages.iter()
+        .zip(pages.iter().cycle().skip(1))
+        .map(|(&before, &after)| {
+            let i = order.iter().position(|&rule| rule == (before, after)).unwrap();
+            format!("{before}|{after} (line {})", i + 1)
+        })
+        .collect();
+    let pages: Vec<String> = pages.iter().map(i32::to_string).collect();
+
+    return format!("rules {} form a cycle through pages {}", rules.join(", "), pages.join(", "));
+}
+
+fn middle_element(slice: &[i32]) -> i32 {
+    slice[slice.len() / 2]
+}
+
+/// What is wrong with an unordered update, and how it gets fixed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Rules `before|after` broken by the update, in the order they are found
+    pub violated: Vec<(i32, i32)>,
+    pub corrected: Vec<i32>,
+    /// Fewest pages to move to turn the update into the corrected order
+    pub moved: Vec<i32>,
+}
+
+/// Pages of `update` out of the longest subsequence it shares with `corrected`, which is the
+/// smallest set of pages that have to be moved to get from one to the other.
+fn moved_pages(update: &[i32], corrected: &[i32]) -> Vec<i32> {
+    let rank: HashMap<i32, usize> = corrected.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+
+    // Longest increasing subsequence of the ranks, `tails[len]` being the index in `update`
+    // of the smallest rank ending a subsequence of length `len + 1`
+    let mut tails: Vec<usize> = vec![];
+    let mut prev: Vec<Option<usize>> = vec![None; update.len()];
+
+    for (i, page) in update.iter().enumerate() {
+        let len = tails.partition_point(|&j| rank[&update[j]] < rank[page]);
+        prev[i] = len.checked_sub(1).map(|len| tails[len]);
+
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut kept = vec![false; update.len()];
+    let mut last = tails.last().copied();
+    while let Some(i) = last {
+        kept[i] = true;
+        last = prev[i];
+    }
+
+    return update.iter()
+        .zip(kept)
+        .filter(|&(_, kept)| !kept)
+        .map(|(&page, _)| page)
+        .collect();
+}
+
+/// Explains why an update is not ordered, or returns `None` if it is.
+pub fn diagnose(update: &[i32], precedence: &Precedence) -> Option<Diagnosis> {
+    let violated: Vec<(i32, i32)> = update.iter()
+        .enumerate()
+        .flat_map(|(i, &page)| {
+            update[..i].iter()
+                .filter(move |&&earlier| precedence.must_precede(page, earlier))
+                .map(move |&earlier| (page, earlier))
+        })
+        .collect();
+
+    if violated.is_empty() {
+        return None;
+    }
+
+    let corrected = topsort(update, precedence).expect("Cycles are rejected when parsing");
+    let moved = moved_pages(update, &corrected);
+
+    return Some(Diagnosis { violated, corrected, moved });
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Precedence, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+        let order = read_order(&mut lines)?;
+        let updates = read_updates(lines)?;
+        let precedence = Precedence::new(&order);
+
+        // Updates follow the rules and the blank line after them
+        for (i, update) in updates.iter().enumerate() {
+            if let Err(cycle) = topsort(update, &precedence) {
+                let line = order.len() + i + 2;
+                return Err(ParseError::invalid(line, input.lines().nth(line - 1).unwrap(), describe_cycle(&cycle, &order)));
+            }
+        }
+
+        Ok((precedence, updates))
+    }
+
+    fn part1((precedence, updates): &Self::Input) -> Answer {
+        let result: i32 = updates.iter()
+            .filter(|update| is_ordered(update, precedence))
+            .map(|update| middle_element(update))
+            .sum();
+
+        result.into()
+    }
+
+    fn part2((precedence, updates): &Self::Input) -> Answer {
+        let result: i32 = updates.iter()
+            .filter(|update| !is_ordered(update, precedence))
+            .map(|update| middle_element(&topsort(update, precedence).expect("Cycles are rejected when parsing")))
+            .sum();
+
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day05>(EXAMPLE), Ok((Answer::Int(143), Answer::Int(123))));
+    }
+
+    #[test]
+    fn precedence_follows_rules_only() {
+        let precedence = Precedence::new(&[(47, 53), (97, 13), (53, 13)]);
+
+        assert!(precedence.must_precede(47, 53));
+        assert!(!precedence.must_precede(53, 47));
+        assert!(!precedence.must_precede(47, 13));
+        assert!(!precedence.must_precede(47, 99));
+    }
+
+    #[test]
+    fn long_rule_chains_are_compiled() {
+        let order: Vec<(i32, i32)> = (0..200_000).map(|page| (page, page + 1)).collect();
+        let precedence = Precedence::new(&order);
+
+        assert!(precedence.must_precede(199_999, 200_000));
+        assert!(!precedence.must_precede(0, 2));
+    }
+
+    #[test]
+    fn diagnoses_unordered_updates() {
+        let (precedence, updates) = Day05::parse(EXAMPLE).unwrap();
+
+        assert_eq!(diagnose(&updates[0], &precedence), None);
+        assert_eq!(diagnose(&updates[3], &precedence), Some(Diagnosis {
+            violated: vec![(97, 75)],
+            corrected: vec![97, 75, 47, 61, 53],
+            moved: vec![75],
+        }));
+
+        let diagnosis = diagnose(&updates[5], &precedence).unwrap();
+        assert_eq!(diagnosis.violated, vec![(75, 13), (29, 13), (47, 13), (47, 29)]);
+        assert_eq!(diagnosis.corrected, vec![97, 75, 47, 29, 13]);
+        assert_eq!(diagnosis.moved, vec![13, 29]);
+    }
+
+    #[test]
+    fn malformed_pages_are_reported() {
+        assert_eq!(Day05::parse("1|2\n3|x\n\n1,2").unwrap_err(), ParseError::invalid_token(2, 3, "x", "a page number"));
+        assert_eq!(Day05::parse("1|2|3\n\n1,2").unwrap_err(), ParseError::wrong_count(1, "1|2|3", "pages", 2, 3));
+        assert_eq!(Day05::parse("1|2\n\n1,,2").unwrap_err(), ParseError::invalid_token(3, 3, "", "a page number"));
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let input = "1|2\n2|3\n3|1\n4|1\n\n4,1,2\n3,2,1";
+        let err = Day05::parse(input).unwrap_err();
+
+        assert_eq!(err.line, 7);
+        assert_eq!(err.to_string(), "line 7: rules 1|2 (line 1), 2|3 (line 2), 3|1 (line 3) form a cycle through pages 1, 2, 3");
+    }
+
+    #[test]
+    fn repeated_pages_are_accepted() {
+        let input = Day05::parse("1|2\n\n3,3\n2,1,2").unwrap();
+
+        assert_eq!(Day05::part1(&input), Answer::from(3));
+    }
+}
@@ -0,0 +1,545 @@
+use std::collections::HashMap;
+use std::thread;
+
+use utils::{read_grid, Answer, Direction, Grid, ParseError, Point, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Obstacle,
+    /// Guard, facing the given direction
+    Start(Direction),
+}
+
+/// What happens to the guard walking past an edge of the area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    /// The guard leaves the area
+    #[default]
+    Bounded,
+    /// The guard re-enters the area from the opposite edge, so that it can never leave and
+    /// walks in a loop whatever obstruction is added
+    Toroidal,
+}
+
+fn to_area_cell(cell: u8) -> Option<Cell> {
+    match cell {
+        b'.' => Some(Cell::Empty),
+        b'#' => Some(Cell::Obstacle),
+        _ => Direction::from_arrow(cell).map(Cell::Start),
+    }
+}
+
+fn convert_area(matrix: &Grid<u8>) -> Result<Grid<Cell>, ParseError> {
+    const GUARD: &str = "a guard (`^`, `>`, `v` or `<`)";
+    let mut guards = 0;
+
+    for (pos, &byte) in matrix.iter() {
+        let error = |expected| {
+            ParseError::invalid_token(pos.y as usize + 1, pos.x as usize + 1, &(byte as char).to_string(), expected)
+        };
+
+        match to_area_cell(byte) {
+            None => return Err(error("`.`, `#` or a guard")),
+            Some(Cell::Start(_)) if guards > 0 => return Err(error("`.` or `#`, there being a single guard")),
+            Some(Cell::Start(_)) => guards += 1,
+            Some(_) => {},
+        }
+    }
+
+    if guards == 0 {
+        return Err(ParseError::invalid_token(matrix.height() + 1, 1, "", GUARD));
+    }
+
+    return Ok(matrix.map(|&b| to_area_cell(b).unwrap()));
+}
+
+fn get_start(area: &Grid<Cell>) -> (Point, Direction) {
+    return area.iter()
+        .find_map(|(pos, cell)| match cell {
+            Cell::Start(dir) => Some((pos, *dir)),
+            _ => None,
+        })
+        .unwrap();
+}
+
+/// Cell ahead of `pos` towards `dir`, or `None` if the guard walks off the area.
+fn ahead(area: &Grid<Cell>, pos: Point, dir: Direction, topology: Topology) -> Option<Point> {
+    let next = pos + dir;
+
+    match topology {
+        _ if area.in_bounds(next) => Some(next),
+        Topology::Bounded => None,
+        Topology::Toroidal => {
+            Some(Point::new(next.x.rem_euclid(area.width() as i32), next.y.rem_euclid(area.height() as i32)))
+        },
+    }
+}
+
+/// How a guard walk finishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The guard walked off the area
+    Exit,
+    /// The guard is back in the state of step `start_step`, repeating the `period` steps since
+    Loop { start_step: usize, period: usize },
+}
+
+/// Walk of the guard from its start, one step at a time, a step being either a move forward
+/// or a turn. Yields the position and direction of the guard after each step, along with
+/// whether it turned, until it leaves the area or would repeat a step.
+///
+/// Steps are numbered from the start, which is step 0.
+pub struct GuardWalk<'a> {
+    area: &'a Grid<Cell>,
+    topology: Topology,
+    pos: Point,
+    dir: Direction,
+    step: usize,
+    /// Step at which the guard was in each state
+    seen: Grid<[Option<usize>; 4]>,
+    outcome: Option<Outcome>,
+}
+
+impl<'a> GuardWalk<'a> {
+    pub fn new(area: &'a Grid<Cell>, topology: Topology) -> Self {
+        let (pos, dir) = get_start(area);
+        let mut seen = Grid::filled(area.width(), area.height(), [None; 4]);
+        seen[pos][dir.index()] = Some(0);
+
+        return Self { area, topology, pos, dir, step: 0, seen, outcome: None };
+    }
+
+    /// How the walk finished, or `None` while it goes on.
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.outcome
+    }
+
+    /// Walks until the end, returning how it finished.
+    pub fn finish(mut self) -> Outcome {
+        self.by_ref().for_each(drop);
+        return self.outcome.unwrap();
+    }
+}
+
+impl Iterator for GuardWalk<'_> {
+    type Item = (Point, Direction, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.outcome.is_some() {
+            return None;
+        }
+
+        let Some(next) = ahead(self.area, self.pos, self.dir, self.topology) else {
+            self.outcome = Some(Outcome::Exit);
+            return None;
+        };
+
+        let (pos, dir, turned) = match self.area[next] {
+            Cell::Obstacle => (self.pos, self.dir.rotate_right(), true),
+            _ => (next, self.dir, false),
+        };
+
+        let seen = &mut self.seen[pos][dir.index()];
+        if let Some(start_step) = *seen {
+            self.outcome = Some(Outcome::Loop { start_step, period: self.step + 1 - start_step });
+            return None;
+        }
+
+        self.step += 1;
+        *seen = Some(self.step);
+        (self.pos, self.dir) = (pos, dir);
+
+        return Some((pos, dir, turned));
+    }
+}
+
+/// For every cell and direction, the number of steps to the next obstacle, or `None` if the
+/// guard walks off the area first, or never meets one when edges wrap around.
+struct Jumps {
+    steps: Grid<[Option<usize>; 4]>,
+    topology: Topology,
+}
+
+impl Jumps {
+    fn new(area: &Grid<Cell>, topology: Topology) -> Self {
+        let mut steps = Grid::filled(area.width(), area.height(), [None; 4]);
+        let positions: Vec<Point> = area.positions().collect();
+
+        // When edges wrap around, a second pass carries the steps across them
+        let passes = match topology {
+            Topology::Bounded => 1,
+            Topology::Toroidal => 2,
+        };
+
+        for dir in Direction::ALL {
+            for _ in 0..passes {
+                // Cells are filled after the one ahead of them, which is earlier in the row-major
+                // order when walking up or left
+                let order: Box<dyn Iterator<Item = &Point>> = match dir {
+                    Direction::Up | Direction::Left => Box::new(positions.iter()),
+                    Direction::Down | Direction::Right => Box::new(positions.iter().rev()),
+                };
+
+                for &pos in order {
+                    steps[pos][dir.index()] = match ahead(area, pos, dir, topology) {
+                        None => None,
+                        Some(next) if area[next] == Cell::Obstacle => Some(1),
+                        Some(next) => steps[next][dir.index()].map(|steps| steps + 1),
+                    };
+                }
+            }
+        }
+
+        return Self { steps, topology };
+    }
+
+    fn obstacle_steps(&self, pos: Point, dir: Direction) -> Option<usize> {
+        self.steps[pos][dir.index()]
+    }
+
+    /// Number of cells in a row, or column, walked along towards `dir`.
+    fn line_length(&self, dir: Direction) -> usize {
+        match dir {
+            Direction::Up | Direction::Down => self.steps.height(),
+            Direction::Left | Direction::Right => self.steps.width(),
+        }
+    }
+
+    /// Number of steps from `from` to `to` walking towards `dir`, if `to` is straight ahead.
+    fn steps_ahead(&self, from: Point, to: Point, dir: Direction) -> Option<usize> {
+        let diff = to - from;
+        let offset = dir.offset();
+        let (along, across) = match dir {
+            Direction::Up | Direction::Down => (diff.y * offset.y, diff.x),
+            Direction::Left | Direction::Right => (diff.x * offset.x, diff.y),
+        };
+        let along = match self.topology {
+            Topology::Bounded => along,
+            Topology::Toroidal => along.rem_euclid(self.line_length(dir) as i32),
+        };
+
+        return (across == 0 && along > 0).then_some(along as usize);
+    }
+
+    /// Position reached after walking `steps` towards `dir`.
+    fn advance(&self, pos: Point, dir: Direction, steps: usize) -> Point {
+        let pos = pos + dir.offset() * steps as i32;
+
+        return Point::new(pos.x.rem_euclid(self.steps.width() as i32), pos.y.rem_euclid(self.steps.height() as i32));
+    }
+}
+
+/// Number of steps in one lap of the loop walked by the guard, standing at `pos` facing `dir`,
+/// once an obstruction is added at `block`, or `None` if the guard leaves the area. Steps are
+/// counted as in `GuardWalk`, turns included, but only turns are simulated, jumping from one
+/// obstacle to the next.
+fn loop_length(jumps: &Jumps, block: Option<Point>, mut pos: Point, mut dir: Direction) -> Option<usize> {
+    let mut turns = HashMap::new();
+    let mut walked = 0;
+
+    loop {
+        let obstacle_steps = jumps.obstacle_steps(pos, dir);
+        let steps = match (block.and_then(|block| jumps.steps_ahead(pos, block, dir)), obstacle_steps) {
+            (Some(steps), _) if obstacle_steps.is_none_or(|max| steps < max) => steps,
+            (_, Some(steps)) => steps,
+            // Nothing stops the guard, who walks off the area or around it forever
+            (_, None) => {
+                return match jumps.topology {
+                    Topology::Bounded => None,
+                    Topology::Toroidal => Some(jumps.line_length(dir)),
+                };
+            },
+        };
+
+        // Walking up to the obstacle, then turning
+        pos = jumps.advance(pos, dir, steps - 1);
+        walked += steps;
+        dir = dir.rotate_right();
+
+        if let Some(first_walked) = turns.insert((pos, dir), walked) {
+            return Some(walked - first_walked);
+        }
+    }
+}
+
+/// Guard's path without any added obstruction.
+struct Path {
+    /// Cells of the path apart from the start, each with the state of the guard just before
+    /// first stepping on it
+    entries: Vec<(Point, Point, Direction)>,
+    visited: Grid<bool>,
+    loops: bool,
+}
+
+fn walk_path(area: &Grid<Cell>, topology: Topology) -> Path {
+    let mut entries = vec![];
+    let mut visited = Grid::filled(area.width(), area.height(), false);
+    let (mut prev, _) = get_start(area);
+    visited[prev] = true;
+
+    let mut walk = GuardWalk::new(area, topology);
+    for (pos, dir, turned) in walk.by_ref() {
+        if !turned && !visited[pos] {
+            visited[pos] = true;
+            entries.push((pos, prev, dir));
+        }
+        prev = pos;
+    }
+
+    let loops = matches!(walk.outcome(), Some(Outcome::Loop { .. }));
+    return Path { entries, visited, loops };
+}
+
+/// Where the guard's patrol leads, without any added obstruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Patrol {
+    /// Distinct cells walked on, the start included
+    pub visited: usize,
+    /// Whether the guard ends up walking in a loop rather than leaving the area
+    pub loops: bool,
+}
+
+pub fn patrol(area: &Grid<Cell>, topology: Topology) -> Patrol {
+    let path = walk_path(area, topology);
+
+    return Patrol { visited: path.entries.len() + 1, loops: path.loops };
+}
+
+/// Cell where an added obstruction traps the guard in a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Obstruction {
+    pub pos: Point,
+    /// Steps in one lap of the resulting loop, turns included as in `Outcome::Loop`
+    pub loop_length: usize,
+}
+
+/// Finds the cells where an obstruction makes the guard loop, row by row. Only cells of the
+/// original path can change it, and the guard walks the same way up to the first time it
+/// reaches them, so each simulation starts from there. Candidates are split between threads.
+///
+/// When the guard already walks in a loop, an obstruction next to the path but off it leaves
+/// the loop untouched, so those cells are found as well. With a toroidal area, every empty
+/// cell is found, as the guard loops wherever the obstruction is: only the loop lengths tell
+/// the cells apart.
+pub fn find_obstructions(area: &Grid<Cell>, topology: Topology) -> Vec<Obstruction> {
+    let jumps = Jumps::new(area, topology);
+    let path = walk_path(area, topology);
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = path.entries.len().div_ceil(threads).max(1);
+
+    let mut obstructions: Vec<Obstruction> = thread::scope(|scope| {
+        let handles: Vec<_> = path.entries.chunks(chunk_size)
+            .map(|chunk| {
+                let jumps = &jumps;
+                scope.spawn(move || {
+                    chunk.iter()
+                        .filter_map(|&(block, pos, dir)| {
+                            let loop_length = loop_length(jumps, Some(block), pos, dir)?;
+                            Some(Obstruction { pos: block, loop_length })
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+
+    if path.loops {
+        let (start, facing) = get_start(area);
+        let loop_length = loop_length(&jumps, None, start, facing).unwrap();
+
+        obstructions.extend(area.positions()
+            .filter(|&pos| area[pos] == Cell::Empty && !path.visited[pos])
+            .filter(|&pos| topology == Topology::Toroidal || area.neighbors4(pos).any(|adj| path.visited[adj]))
+            .map(|pos| Obstruction { pos, loop_length }));
+    }
+
+    obstructions.sort_unstable_by_key(|obstruction| (obstruction.pos.y, obstruction.pos.x));
+    return obstructions;
+}
+
+/// Draws the area as in the puzzle statement, with the obstructions marked `O`.
+pub fn draw_obstructions(area: &Grid<Cell>, obstructions: &[Obstruction]) -> String {
+    let mut drawing = area.map(|cell| match cell {
+        Cell::Empty => '.',
+        Cell::Obstacle => '#',
+        Cell::Start(dir) => dir.to_arrow() as char,
+    });
+
+    for obstruction in obstructions {
+        drawing[obstruction.pos] = 'O';
+    }
+
+    return drawing.rows()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid<Cell>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        convert_area(&read_grid(input)?)
+    }
+
+    fn part1(area: &Self::Input) -> Answer {
+        let patrol = patrol(area, Topology::Bounded);
+        let result = if patrol.loops { -1 } else { patrol.visited as i64 };
+
+        result.into()
+    }
+
+    fn part2(area: &Self::Input) -> Answer {
+        find_obstructions(area, Topology::Bounded).len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day06>(EXAMPLE), Ok((Answer::Int(41), Answer::Int(6))));
+    }
+
+    #[test]
+    fn jumps_stop_in_front_of_obstacles() {
+        let area = Day06::parse(EXAMPLE).unwrap();
+        let jumps = Jumps::new(&area, Topology::Bounded);
+
+        assert_eq!(jumps.obstacle_steps(Point::new(4, 6), Direction::Up), Some(6));
+        assert_eq!(jumps.obstacle_steps(Point::new(4, 1), Direction::Right), Some(5));
+        assert_eq!(jumps.obstacle_steps(Point::new(0, 0), Direction::Left), None);
+        assert_eq!(loop_length(&jumps, Some(Point::new(3, 6)), Point::new(4, 6), Direction::Left), Some(22));
+        assert_eq!(loop_length(&jumps, None, Point::new(4, 6), Direction::Up), None);
+    }
+
+    #[test]
+    fn obstructions_are_listed_and_drawn() {
+        let area = Day06::parse(EXAMPLE).unwrap();
+        let obstructions = find_obstructions(&area, Topology::Bounded);
+        let positions: Vec<(i32, i32)> = obstructions.iter().map(|found| (found.pos.x, found.pos.y)).collect();
+
+        assert_eq!(positions, vec![(3, 6), (6, 7), (7, 7), (1, 8), (3, 8), (7, 9)]);
+        assert_eq!(draw_obstructions(&area, &obstructions), "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#.O^.....
+......OO#.
+#O.O......
+......#O..");
+    }
+
+    #[test]
+    fn loop_lengths_match_guard_walks() {
+        for (input, topology) in [
+            (EXAMPLE, Topology::Bounded),
+            (".....\n##...\n#..#.\n.^#..", Topology::Bounded),
+            (".#.\n...\n.^.", Topology::Toroidal),
+        ] {
+            let area = Day06::parse(input).unwrap();
+
+            for obstruction in find_obstructions(&area, topology) {
+                let mut blocked = area.clone();
+                blocked[obstruction.pos] = Cell::Obstacle;
+
+                let Outcome::Loop { period, .. } = GuardWalk::new(&blocked, topology).finish() else {
+                    panic!("No loop with an obstruction at {:?}", obstruction.pos);
+                };
+                assert_eq!(period, obstruction.loop_length);
+            }
+        }
+    }
+
+    #[test]
+    fn guard_already_walking_in_a_loop() {
+        let input = "\
+.....
+##...
+#..#.
+.^#..";
+
+        assert_eq!(solve::<Day06>(input), Ok((Answer::Int(-1), Answer::Int(2))));
+
+        let area = Day06::parse(input).unwrap();
+        let steps: Vec<_> = GuardWalk::new(&area, Topology::Bounded).collect();
+        assert_eq!(steps[..3], [
+            (Point::new(1, 2), Direction::Up, false),
+            (Point::new(1, 2), Direction::Right, true),
+            (Point::new(2, 2), Direction::Right, false),
+        ]);
+        assert_eq!(steps.len(), 6);
+        assert_eq!(GuardWalk::new(&area, Topology::Bounded).finish(), Outcome::Loop { start_step: 1, period: 6 });
+    }
+
+    #[test]
+    fn guard_walk_exits() {
+        let area = Day06::parse(EXAMPLE).unwrap();
+        let mut walk = GuardWalk::new(&area, Topology::Bounded);
+
+        assert_eq!(walk.next(), Some((Point::new(4, 5), Direction::Up, false)));
+        assert_eq!(walk.outcome(), None);
+        assert_eq!(walk.by_ref().filter(|&(_, _, turned)| turned).count(), 10);
+        assert_eq!(walk.outcome(), Some(Outcome::Exit));
+    }
+
+    #[test]
+    fn guard_may_face_any_direction() {
+        let turned = EXAMPLE.replace('^', ">");
+        let area = Day06::parse(&turned).unwrap();
+
+        assert_eq!(patrol(&area, Topology::Bounded), Patrol { visited: 6, loops: false });
+    }
+
+    #[test]
+    fn unknown_cells_are_reported() {
+        assert_eq!(Day06::parse("..\n.x"), Err(ParseError::invalid_token(2, 2, "x", "`.`, `#` or a guard")));
+        assert_eq!(Day06::parse("^.\n.^").unwrap_err().column, 2);
+        assert_eq!(Day06::parse("..\n.#").unwrap_err().line, 3);
+    }
+
+    #[test]
+    fn toroidal_area_wraps_around() {
+        let area = Day06::parse(".#.\n...\n.^.").unwrap();
+        assert_eq!(patrol(&area, Topology::Bounded), Patrol { visited: 3, loops: false });
+        assert_eq!(patrol(&area, Topology::Toroidal), Patrol { visited: 4, loops: true });
+
+        let jumps = Jumps::new(&area, Topology::Toroidal);
+        assert_eq!(jumps.obstacle_steps(Point::new(1, 2), Direction::Up), Some(2));
+        assert_eq!(jumps.obstacle_steps(Point::new(1, 1), Direction::Down), Some(2));
+        assert_eq!(loop_length(&jumps, None, Point::new(1, 2), Direction::Up), Some(3));
+        assert_eq!(find_obstructions(&area, Topology::Toroidal).len(), 7);
+    }
+
+    #[test]
+    fn toroidal_area_loops_with_any_obstruction() {
+        let area = Day06::parse(".#...\n.....\n.....\n.^...").unwrap();
+        let obstructions = find_obstructions(&area, Topology::Toroidal);
+
+        assert_eq!(obstructions.len(), 18);
+        assert_eq!(obstructions.iter().filter(|found| found.loop_length == 4).count(), 3);
+    }
+}
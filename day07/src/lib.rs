@@ -0,0 +1,434 @@
+use std::fmt;
+
+use utils::error::{parse_token, tokens};
+use utils::{Answer, ParseError, Solution};
+
+/// Calibration equation, with 128-bit numbers to fit large calibrations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    result: u128,
+    operands: Vec<u128>,
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.result)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))
+    }
+}
+
+fn parse_equation(line_num: usize, line: &str) -> Result<Equation, ParseError> {
+    const NUMBER: &str = "a non-negative integer";
+
+    let Some((result, operands)) = line.split_once(':') else {
+        return Err(ParseError::invalid_token(line_num, line.len() + 1, "", "`:`"));
+    };
+
+    let mut result_tokens = tokens(result);
+    let (column, result) = result_tokens.next()
+        .ok_or_else(|| ParseError::invalid_token(line_num, 1, ":", NUMBER))?;
+    let result = parse_token(line_num, column, result, NUMBER)?;
+
+    if let Some((column, word)) = result_tokens.next() {
+        return Err(ParseError::invalid_token(line_num, column, word, "`:`"));
+    }
+
+    // Operand columns are counted from the start of the line
+    let offset = line.len() - operands.len();
+    let operands: Vec<u128> = tokens(operands)
+        .map(|(column, word)| parse_token(line_num, offset + column, word, NUMBER))
+        .collect::<Result<_, _>>()?;
+
+    if operands.is_empty() {
+        return Err(ParseError::invalid_token(line_num, line.len() + 1, "", "an operand"));
+    }
+
+    return Ok(Equation { result, operands });
+}
+
+pub fn read_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    return input.lines()
+        .enumerate()
+        .map(|(i, line)| parse_equation(i + 1, line))
+        .collect();
+}
+
+/// Smallest power of ten above `num`, by which a number is shifted to append `num` to it, or
+/// `None` if it does not fit.
+fn digit_shift(num: u128) -> Option<u128> {
+    let mut shift: u128 = 10;
+
+    while shift <= num {
+        shift = shift.checked_mul(10)?;
+    }
+
+    return Some(shift);
+}
+
+/// Appends the digits of `b` to `a`, or returns `None` on overflow.
+fn concat_nums(a: u128, b: u128) -> Option<u128> {
+    a.checked_mul(digit_shift(b)?)?.checked_add(b)
+}
+
+/// Binary operator placed between two operands of an equation, evaluated left to right.
+/// Left operands `a` such that `apply(a, b) == Some(result)`, for some operator, `result` and `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inverse {
+    None,
+    One(u128),
+    /// Any `a` works, as when multiplying by zero
+    Any,
+}
+
+pub trait Operator {
+    fn symbol(&self) -> &'static str;
+
+    /// `a` and `b` combined, or `None` on overflow, which exceeds any result.
+    fn apply(&self, a: u128, b: u128) -> Option<u128>;
+
+    /// The `a` such that `apply(a, b) == Some(result)`.
+    fn invert(&self, result: u128, b: u128) -> Inverse;
+}
+
+pub struct Add;
+pub struct Mul;
+pub struct Concat;
+
+impl Operator for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn apply(&self, a: u128, b: u128) -> Option<u128> {
+        a.checked_add(b)
+    }
+
+    fn invert(&self, result: u128, b: u128) -> Inverse {
+        result.checked_sub(b).map_or(Inverse::None, Inverse::One)
+    }
+}
+
+impl Operator for Mul {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+
+    fn apply(&self, a: u128, b: u128) -> Option<u128> {
+        a.checked_mul(b)
+    }
+
+    fn invert(&self, result: u128, b: u128) -> Inverse {
+        match b {
+            // Multiplying by zero loses `a`, which may then be anything
+            0 if result == 0 => Inverse::Any,
+            0 => Inverse::None,
+            _ if result.is_multiple_of(b) => Inverse::One(result / b),
+            _ => Inverse::None,
+        }
+    }
+}
+
+impl Operator for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+
+    fn apply(&self, a: u128, b: u128) -> Option<u128> {
+        concat_nums(a, b)
+    }
+
+    fn invert(&self, result: u128, b: u128) -> Inverse {
+        match digit_shift(b) {
+            Some(shift) if result % shift == b => Inverse::One(result / shift),
+            _ => Inverse::None,
+        }
+    }
+}
+
+/// Operators of part 1.
+pub const ADD_MUL: &[&dyn Operator] = &[&Add, &Mul];
+/// Operators of part 2.
+pub const ADD_MUL_CONCAT: &[&dyn Operator] = &[&Add, &Mul, &Concat];
+
+/// Searches backwards from the result, undoing the operators from the last operand to the
+/// second, and calls `found` on every assignment leaving exactly the first operand, for as long
+/// as it returns `true`. Operators are chosen from the right. Returns whether to go on searching.
+///
+/// Once any value works for the operands left, as when the next is multiplied by zero, every
+/// assignment of them is tried forwards instead, only overflows ruling some out.
+fn search<'a>(
+    operands: &[u128],
+    result: u128,
+    operators: &[&'a dyn Operator],
+    chosen: &mut Vec<&'a dyn Operator>,
+    found: &mut impl FnMut(&[&'a dyn Operator]) -> bool,
+) -> bool {
+    let (&last, rest) = operands.split_last().unwrap();
+
+    if rest.is_empty() {
+        return last != result || found(chosen);
+    }
+
+    for &operator in operators {
+        chosen.push(operator);
+        let go_on = match operator.invert(result, last) {
+            Inverse::None => true,
+            Inverse::One(rest_result) => search(rest, rest_result, operators, chosen, found),
+            Inverse::Any => {
+                let (&first, rest) = rest.split_first().unwrap();
+                let chosen = &*chosen;
+
+                assign_all(rest, first, operators, &mut vec![], &mut |assigned| {
+                    let assignment: Vec<_> = chosen.iter().chain(assigned.iter().rev()).copied().collect();
+                    found(&assignment)
+                })
+            },
+        };
+        chosen.pop();
+
+        if !go_on {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+/// Calls `found` on every assignment of operators to `operands`, applied from left to right
+/// after `acc`, which does not overflow, for as long as it returns `true`. Returns whether to go
+/// on searching.
+fn assign_all<'a>(
+    operands: &[u128],
+    acc: u128,
+    operators: &[&'a dyn Operator],
+    assigned: &mut Vec<&'a dyn Operator>,
+    found: &mut impl FnMut(&[&'a dyn Operator]) -> bool,
+) -> bool {
+    let Some((&next, rest)) = operands.split_first() else {
+        return found(assigned);
+    };
+
+    for &operator in operators {
+        let Some(acc) = operator.apply(acc, next) else {
+            continue;
+        };
+
+        assigned.push(operator);
+        let go_on = assign_all(rest, acc, operators, assigned, found);
+        assigned.pop();
+
+        if !go_on {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+/// Operators making an equation true, placed between its operands from left to right.
+pub struct Witness<'a> {
+    pub equation: &'a Equation,
+    pub operators: Vec<&'a dyn Operator>,
+}
+
+impl Witness<'_> {
+    /// Applies the operators to the operands, which gives back the result.
+    pub fn evaluate(&self) -> Option<u128> {
+        let operands = &self.equation.operands;
+
+        return self.operators.iter()
+            .zip(&operands[1..])
+            .try_fold(operands[0], |acc, (operator, &operand)| operator.apply(acc, operand));
+    }
+}
+
+impl fmt::Display for Witness<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Equation { result, operands } = self.equation;
+        write!(f, "{result} = {}", operands[0])?;
+
+        self.operators.iter()
+            .zip(&operands[1..])
+            .try_for_each(|(operator, operand)| write!(f, " {} {operand}", operator.symbol()))
+    }
+}
+
+/// First assignment of operators found making the equation true, trying them in the given
+/// order from the last operand backwards.
+pub fn find_witness<'a>(equation: &'a Equation, operators: &[&'a dyn Operator]) -> Option<Witness<'a>> {
+    let mut witness = None;
+
+    search(&equation.operands, equation.result, operators, &mut vec![], &mut |chosen| {
+        witness = Some(Witness { equation, operators: chosen.iter().rev().copied().collect() });
+        false
+    });
+
+    return witness;
+}
+
+/// Number of distinct assignments of operators making the equation true.
+pub fn count_solutions(equation: &Equation, operators: &[&dyn Operator]) -> usize {
+    let mut count = 0;
+
+    search(&equation.operands, equation.result, operators, &mut vec![], &mut |_| {
+        count += 1;
+        true
+    });
+
+    return count;
+}
+
+fn is_possible(equation: &Equation, operators: &[&dyn Operator]) -> bool {
+    find_witness(equation, operators).is_some()
+}
+
+fn calibration_result(equations: &[Equation], operators: &[&dyn Operator]) -> u128 {
+    return equations.iter()
+        .filter(|eq| is_possible(eq, operators))
+        .try_fold(0u128, |sum, eq| sum.checked_add(eq.result))
+        .expect("Overflow is checked when parsing");
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let equations = read_equations(input)?;
+
+        // Calibration results only add some of the equations, so they fit if all of them do
+        let mut total: u128 = 0;
+        for (i, (equation, line)) in equations.iter().zip(input.lines()).enumerate() {
+            total = total.checked_add(equation.result)
+                .ok_or_else(|| ParseError::invalid(i + 1, line, "calibration result overflows a 128-bit integer".to_string()))?;
+        }
+
+        return Ok(equations);
+    }
+
+    fn part1(equations: &Self::Input) -> Answer {
+        calibration_result(equations, ADD_MUL).into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        calibration_result(equations, ADD_MUL_CONCAT).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day07>(EXAMPLE), Ok((Answer::Int(3749), Answer::Int(11387))));
+    }
+
+    #[test]
+    fn concat_nums_appends_digits() {
+        assert_eq!(concat_nums(12, 345), Some(12345));
+        assert_eq!(concat_nums(15, 6), Some(156));
+        assert_eq!(concat_nums(1, 0), Some(10));
+        assert_eq!(concat_nums(5, 100), Some(5100));
+        assert_eq!(concat_nums(0, 7), Some(7));
+        assert_eq!(concat_nums(u128::MAX / 10, 9), None);
+        assert_eq!(concat_nums(1, u128::MAX), None);
+    }
+
+    #[test]
+    fn overflow_exceeds_the_result() {
+        let big = u64::MAX as u128;
+        let input = format!("{}: {big} {big} 1\n{}: {} 10 1", big * big, u128::MAX, u128::MAX / 10);
+        let equations = read_equations(&input).unwrap();
+
+        let witness = find_witness(&equations[0], ADD_MUL).unwrap();
+        assert_eq!(witness.to_string(), format!("{} = {big} * {big} * 1", big * big));
+        assert_eq!(witness.evaluate(), Some(big * big));
+        assert_eq!(Mul.apply(big * big, 4), None);
+        assert!(find_witness(&equations[1], ADD_MUL_CONCAT).is_none());
+    }
+
+    #[test]
+    fn parse_errors_point_to_the_line() {
+        assert_eq!(read_equations("1: 1\n12 3").unwrap_err(), ParseError::invalid_token(2, 5, "", "`:`"));
+        assert_eq!(read_equations("1: 1\n12: 3 x4").unwrap_err(), ParseError::invalid_token(2, 7, "x4", "a non-negative integer"));
+        assert_eq!(read_equations("-5: 1").unwrap_err(), ParseError::invalid_token(1, 1, "-5", "a non-negative integer"));
+        assert_eq!(read_equations("5:").unwrap_err(), ParseError::invalid_token(1, 3, "", "an operand"));
+        assert_eq!(read_equations("5 x: 2").unwrap_err(), ParseError::invalid_token(1, 3, "x", "`:`"));
+
+        let input = format!("1: 1\n{max}: {max}\n2: 2", max = u128::MAX);
+        let err = Day07::parse(&input).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.to_string(), "line 2: calibration result overflows a 128-bit integer");
+    }
+
+    #[test]
+    fn witnesses_and_solution_counts() {
+        let equations = read_equations(EXAMPLE).unwrap();
+
+        assert_eq!(find_witness(&equations[1], ADD_MUL).unwrap().to_string(), "3267 = 81 * 40 + 27");
+        assert_eq!(find_witness(&equations[4], ADD_MUL_CONCAT).unwrap().to_string(), "7290 = 6 * 8 || 6 * 15");
+        assert!(find_witness(&equations[4], ADD_MUL).is_none());
+        assert_eq!(count_solutions(&equations[1], ADD_MUL), 2);
+        assert_eq!(count_solutions(&equations[3], ADD_MUL_CONCAT), 1);
+        assert_eq!(count_solutions(&equations[2], ADD_MUL_CONCAT), 0);
+    }
+
+    #[test]
+    fn multiplying_by_zero_allows_any_prefix() {
+        let input = format!("10: 5 0 10\n0: 7 3 0\n0: {} 10 0", u128::MAX);
+        let equations = read_equations(&input).unwrap();
+
+        assert_eq!(find_witness(&equations[0], ADD_MUL).unwrap().to_string(), "10 = 5 * 0 + 10");
+        assert_eq!(find_witness(&equations[1], ADD_MUL).unwrap().to_string(), "0 = 7 + 3 * 0");
+        assert_eq!(count_solutions(&equations[0], ADD_MUL_CONCAT), 2);
+        assert_eq!(count_solutions(&equations[1], ADD_MUL), 2);
+        assert_eq!(count_solutions(&equations[1], ADD_MUL_CONCAT), 3);
+        assert_eq!(count_solutions(&equations[2], ADD_MUL_CONCAT), 0);
+        assert_eq!(Mul.invert(0, 0), Inverse::Any);
+        assert_eq!(Mul.invert(5, 0), Inverse::None);
+        assert_eq!(solve::<Day07>("10: 5 0 10"), Ok((Answer::Int(10), Answer::Int(10))));
+    }
+
+    struct Xor;
+
+    impl Operator for Xor {
+        fn symbol(&self) -> &'static str {
+            "^"
+        }
+
+        fn apply(&self, a: u128, b: u128) -> Option<u128> {
+            Some(a ^ b)
+        }
+
+        fn invert(&self, result: u128, b: u128) -> Inverse {
+            Inverse::One(result ^ b)
+        }
+    }
+
+    #[test]
+    fn operators_can_be_plugged_in() {
+        let equations = read_equations("6: 5 3\n13: 6 3 8").unwrap();
+        let operators: &[&dyn Operator] = &[&Add, &Mul, &Xor];
+
+        let witness = find_witness(&equations[0], operators).unwrap();
+        assert_eq!(witness.to_string(), "6 = 5 ^ 3");
+        assert_eq!(witness.evaluate(), Some(6));
+
+        assert_eq!(find_witness(&equations[1], operators).unwrap().to_string(), "13 = 6 ^ 3 + 8");
+        assert_eq!(count_solutions(&equations[1], operators), 2);
+    }
+}
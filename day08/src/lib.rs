@@ -0,0 +1,120 @@
+use utils::{read_grid, Answer, Grid, ParseError, Point, Solution};
+use std::collections::HashMap;
+
+fn get_frequency_map(map: &Grid<u8>) -> HashMap<u8, Vec<Point>> {
+    let mut res = HashMap::new();
+
+    for (pos, &c) in map.iter() {
+        if c != b'.' {
+            res.entry(c)
+                .or_insert(vec![])
+                .push(pos);
+        }
+    }
+
+    return res;
+}
+
+fn count_antinodes(map: &Grid<u8>, frequency_map: &HashMap<u8, Vec<Point>>) -> usize {
+    let mut count = 0;
+    let mut is_antinode = map.map(|_| false);
+
+    for (_, points) in frequency_map.iter() {
+        let pairs = points.iter().enumerate()
+            .flat_map(|(i, p1)| points[i + 1..].iter().map(move |p2| (p1, p2)));
+
+        for (&p1, &p2) in pairs {
+            let diff = p2 - p1;
+
+            for pos in [p1 - diff, p2 + diff] {
+                if is_antinode.get(pos).is_some_and(|antinode| !antinode) {
+                    count += 1;
+                    is_antinode[pos] = true;
+                }
+            }
+        }
+    }
+
+    return count;
+}
+
+fn get_line_antinodes(map: &Grid<u8>, p1: Point, p2: Point) -> impl Iterator<Item = Point> + '_ {
+    let diff = p2 - p1;
+
+    let it1 = (0..)
+        .map(move |i| p1 - diff * i)
+        .take_while(|&pos| map.in_bounds(pos));
+
+    let it2 = (0..)
+        .map(move |i| p2 + diff * i)
+        .take_while(|&pos| map.in_bounds(pos));
+
+    return it1.chain(it2);
+}
+
+fn count_antinodes2(map: &Grid<u8>, frequency_map: &HashMap<u8, Vec<Point>>) -> usize {
+    let mut count = 0;
+    let mut is_antinode = map.map(|_| false);
+
+    for (_, points) in frequency_map.iter() {
+        let pairs = points.iter().enumerate()
+            .flat_map(|(i, p1)| points[i + 1..].iter().map(move |p2| (p1, p2)));
+
+        for (&p1, &p2) in pairs {
+            for pos in get_line_antinodes(map, p1, p2) {
+                if !is_antinode[pos] {
+                    count += 1;
+                    is_antinode[pos] = true;
+                }
+            }
+        }
+    }
+
+    return count;
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Grid<u8>, HashMap<u8, Vec<Point>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = read_grid(input)?;
+        let frequency_map = get_frequency_map(&map);
+
+        Ok((map, frequency_map))
+    }
+
+    fn part1((map, frequency_map): &Self::Input) -> Answer {
+        count_antinodes(map, frequency_map).into()
+    }
+
+    fn part2((map, frequency_map): &Self::Input) -> Answer {
+        count_antinodes2(map, frequency_map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day08>(EXAMPLE), Ok((Answer::Int(14), Answer::Int(34))));
+    }
+}
@@ -0,0 +1,210 @@
+use utils::{Answer, ParseError, Solution};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Block {
+    start: usize,
+    id: i32,
+    size: u8
+}
+
+impl Block {
+    fn new(id: i32, start: usize, size: u8) -> Self {
+        Self {
+            id,
+            start,
+            size,
+        }
+    }
+}
+
+fn to_disk_map(line: &str) -> Result<Vec<u8>, ParseError> {
+    let mut disk_map: Vec<u8> = line.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(ParseError::invalid_token(1, i + 1, &c.to_string(), "a digit")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    if disk_map.is_empty() {
+        return Err(ParseError::invalid_token(1, 1, "", "a digit"));
+    }
+
+    // Free space after the last file is never used
+    if disk_map.len().is_multiple_of(2) {
+        disk_map.pop();
+    }
+
+    return Ok(disk_map);
+}
+
+fn compact(disk_map: &[u8]) -> impl Iterator<Item = Block> {
+    let mut disk_map: Vec<u8> = disk_map.to_vec();
+
+    let mut i = 0;
+    while i < disk_map.len() && disk_map[i] == 0 {
+        i += 1;
+    }
+
+    let mut j = disk_map.len() - 1;
+    while i <= j && disk_map[j] == 0 {
+        j -= 2;
+    }
+
+    let mut pos = 0;
+    return std::iter::from_fn(move || {
+        if i <= j {
+            if i % 2 == 0 {
+                let next = Some(Block::new(i as i32 / 2, pos, disk_map[i]));
+                pos += disk_map[i] as usize;
+
+                i += 1;
+                while i <= j && disk_map[i] == 0 {
+                    i += 1;
+                }
+
+                return next;
+
+            } else if disk_map[j] < disk_map[i] {
+                let next = Some(Block::new(j as i32 / 2, pos, disk_map[j]));
+                pos += disk_map[j] as usize;
+
+                disk_map[i] -= disk_map[j];
+                j -= 2;
+                while i <= j && disk_map[j] == 0 {
+                    j -= 2;
+                }
+
+                return next;
+
+            } else {
+                let next = Some(Block::new(j as i32 / 2, pos, disk_map[i]));
+                pos += disk_map[i] as usize;
+
+                disk_map[j] -= disk_map[i];
+                i += 1;
+                while i <= j && disk_map[i] == 0 {
+                    i += 1;
+                }
+
+                while i <= j && disk_map[j] == 0 {
+                    j -= 2;
+                }
+
+                return next;
+            }
+        } else {
+            return None;
+        }
+    });
+}
+
+fn to_blocks(disk_map: &[u8]) -> impl Iterator<Item = Block> + '_ {
+    let mut pos = 0;
+
+    return disk_map.iter()
+        .enumerate()
+        .map(move |(i, &size)| {
+            let next = Block::new(if i % 2 == 0 { i as i32 / 2 } else { -1 }, pos, size);
+            pos += size as usize;
+            return next;
+        });
+}
+
+fn compact_whole(disk_map: &[u8]) -> Vec<Block> {
+    let mut block_disk: Vec<Block> = to_blocks(disk_map).collect();
+
+    let mut j = block_disk.len() - 1;
+
+    loop {
+        let free_index = (0..j)
+            .find(|&i| block_disk[i].id == -1 && block_disk[i].size >= block_disk[j].size);
+
+        if let Some(i) = free_index {
+            let new_block1 = Block::new(block_disk[j].id, block_disk[i].start, block_disk[j].size);
+
+            if block_disk[i].size > block_disk[j].size {
+                let new_block2 = Block::new(-1, block_disk[i].start + block_disk[j].size as usize, block_disk[i].size - block_disk[j].size);
+                block_disk.splice(i..i + 1, [new_block1, new_block2]);
+                j += 1;
+            } else {
+                block_disk[i] = new_block1;
+            }
+            block_disk[j].id = -1;
+        }
+
+        if j == 0 {
+            break;
+        }
+        j -= 1;
+        while block_disk[j].id == -1 {
+            j -= 1;
+        }
+    }
+
+    return block_disk;
+}
+
+fn get_checksum<I: Iterator<Item = Block>>(compact_disk: I) -> usize {
+    return compact_disk.fold(0, |checksum, Block { id, start, size}| {
+        if id > 0 && size > 0 { checksum + id as usize * (2 * start + size as usize - 1) * size as usize / 2 } else { checksum }
+    });
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        to_disk_map(input.trim())
+    }
+
+    fn part1(disk_map: &Self::Input) -> Answer {
+        get_checksum(compact(disk_map)).into()
+    }
+
+    fn part2(disk_map: &Self::Input) -> Answer {
+        get_checksum(compact_whole(disk_map).into_iter()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+2333133121414131402";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day09>(EXAMPLE), Ok((Answer::Int(1928), Answer::Int(2858))));
+    }
+
+    #[test]
+    fn trailing_newline_is_ignored() {
+        assert_eq!(solve::<Day09>("12345\n"), Ok((Answer::Int(60), Answer::Int(132))));
+    }
+
+    #[test]
+    fn non_digits_are_reported() {
+        assert_eq!(Day09::parse("12é3"), Err(ParseError::invalid_token(1, 3, "é", "a digit")));
+    }
+
+    #[test]
+    fn empty_disk_maps_are_reported() {
+        assert_eq!(Day09::parse("\n"), Err(ParseError::invalid_token(1, 1, "", "a digit")));
+    }
+
+    #[test]
+    fn empty_files_are_allowed() {
+        assert_eq!(solve::<Day09>("0"), Ok((Answer::Int(0), Answer::Int(0))));
+        assert_eq!(solve::<Day09>("01010"), Ok((Answer::Int(0), Answer::Int(0))));
+        assert_eq!(solve::<Day09>("1020031"), Ok((Answer::Int(12), Answer::Int(12))));
+    }
+
+    #[test]
+    fn trailing_free_space_is_ignored() {
+        assert_eq!(solve::<Day09>("123"), solve::<Day09>("1234"));
+    }
+}
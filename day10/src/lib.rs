@@ -0,0 +1,71 @@
+use utils::graph::{bfs, count_paths};
+use utils::{read_grid, Answer, Grid, ParseError, Point, Solution};
+
+fn get_uphill_adjs(map: &Grid<u8>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+    let curr_level = map[pos];
+
+    return map.neighbors4(pos)
+        .filter(move |&adj| map[adj] == curr_level + 1);
+}
+
+fn get_trailheads(map: &Grid<u8>) -> impl Iterator<Item = Point> + '_ {
+    return map.iter()
+        .filter(|(_, &c)| c == b'0')
+        .map(|(pos, _)| pos);
+}
+
+fn get_trailhead_scores(map: &Grid<u8>) -> usize {
+    return get_trailheads(map)
+        .map(|start| {
+            bfs(start, |pos| get_uphill_adjs(map, pos))
+                .into_keys()
+                .filter(|&pos| map[pos] == b'9')
+                .count()
+        })
+        .sum();
+}
+
+fn get_trailhead_ratings(map: &Grid<u8>) -> usize {
+    return get_trailheads(map)
+        .map(|start| count_paths(start, |pos| map[pos] == b'9', |pos| get_uphill_adjs(map, pos)))
+        .sum();
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_grid(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        get_trailhead_scores(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        get_trailhead_ratings(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day10>(EXAMPLE), Ok((Answer::Int(36), Answer::Int(81))));
+    }
+}
@@ -1,4 +1,4 @@
-use utils::read_usize_list;
+use utils::{read_usize_list, Answer, ParseError, Solution};
 use std::collections::HashMap;
 
 fn count_digits(mut num: usize) -> usize {
@@ -50,7 +50,7 @@ fn evolve_stone(stone: usize, blinks: usize, cache: &mut HashMap<(usize, usize),
         return get_result(1, blinks - 1, cache);
     }
 
-    if count_digits(stone) % 2 == 0 {
+    if count_digits(stone).is_multiple_of(2) {
         let (stone1, stone2) = split_in_half(stone);
         return get_result(stone1, blinks - 1, cache) + get_result(stone2, blinks - 1, cache);
     }
@@ -58,17 +58,51 @@ fn evolve_stone(stone: usize, blinks: usize, cache: &mut HashMap<(usize, usize),
     return get_result(stone * 2024, blinks - 1, cache);
 }
 
-fn main() {
-    let stones = read_usize_list();
+fn count_stones(stones: &[usize], blinks: usize) -> usize {
     let mut cache = HashMap::new();
 
-    let result1: usize = stones.iter()
-        .map(|&stone| evolve_stone(stone, 25, &mut cache))
-        .sum();
-    let result2: usize = stones.iter()
-        .map(|&stone| evolve_stone(stone, 75, &mut cache))
+    return stones.iter()
+        .map(|&stone| evolve_stone(stone, blinks, &mut cache))
         .sum();
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_usize_list(input)
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        count_stones(stones, 25).into()
+    }
 
-    println!("Part 1 result: {result1}");
-    println!("Part 2 result: {result2}");
+    fn part2(stones: &Self::Input) -> Answer {
+        count_stones(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+125 17";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day11>(EXAMPLE), Ok((Answer::Int(55312), Answer::Int(65601038650482))));
+    }
+
+    #[test]
+    fn split_in_half_keeps_zeros() {
+        assert_eq!(split_in_half(17), (1, 7));
+        assert_eq!(split_in_half(2024), (20, 24));
+        assert_eq!(split_in_half(1000), (10, 0));
+        assert_eq!(split_in_half(100100), (100, 100));
+        assert_eq!(split_in_half(1203), (12, 3));
+    }
 }
@@ -0,0 +1,124 @@
+use utils::graph::connected_components;
+use utils::{read_grid, Answer, Grid, ParseError, Point, Solution};
+
+fn get_adjs(map: &Grid<u8>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+    let cell = map[pos];
+
+    return map.neighbors4(pos)
+        .filter(move |&adj| map[adj] == cell);
+}
+
+fn get_regions(map: &Grid<u8>) -> Vec<Vec<Point>> {
+    return connected_components(map.positions(), |pos| get_adjs(map, pos));
+}
+
+fn get_price1(map: &Grid<u8>) -> usize {
+    return get_regions(map).iter()
+        .map(|region| {
+            let perimeter: usize = region.iter()
+                .map(|&pos| 4 - get_adjs(map, pos).count())
+                .sum();
+
+            perimeter * region.len()
+        })
+        .sum();
+}
+
+fn count_corners(map: &Grid<u8>, pos: Point) -> usize {
+    let cell = map.get(pos);
+
+    return [(1, 1), (-1, 1), (-1, -1), (1, -1)].into_iter()
+        .map(|(dx, dy)| (map.get(pos + Point::new(dx, dy)), map.get(pos + Point::new(0, dy)), map.get(pos + Point::new(dx, 0))))
+        .filter(|&(diag, side1, side2)| (diag != cell && side1 == cell && side2 == cell) || (side1 != cell && side2 != cell))
+        .count(); 
+}
+
+fn get_price2(map: &Grid<u8>) -> usize {
+    return get_regions(map).iter()
+        .map(|region| {
+            let sides: usize = region.iter()
+                .map(|&pos| count_corners(map, pos))
+                .sum();
+
+            sides * region.len()
+        })
+        .sum();
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_grid(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        get_price1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        get_price2(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    const SMALL_EXAMPLE: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    const E_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+    const AB_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day12>(EXAMPLE), Ok((Answer::Int(1930), Answer::Int(1206))));
+    }
+
+    #[test]
+    fn small_examples() {
+        assert_eq!(solve::<Day12>(SMALL_EXAMPLE), Ok((Answer::Int(140), Answer::Int(80))));
+        assert_eq!(solve::<Day12>(E_EXAMPLE), Ok((Answer::Int(692), Answer::Int(236))));
+        assert_eq!(solve::<Day12>(AB_EXAMPLE), Ok((Answer::Int(1184), Answer::Int(368))));
+    }
+
+    #[test]
+    fn count_corners_of_convex_and_concave_cells() {
+        let map = read_grid("AAA\nABA\nAAA").unwrap();
+
+        assert_eq!(count_corners(&map, Point::new(1, 1)), 4);
+        assert_eq!(count_corners(&map, Point::new(0, 0)), 2);
+        assert_eq!(count_corners(&map, Point::new(1, 0)), 0);
+    }
+}
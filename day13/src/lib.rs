@@ -0,0 +1,168 @@
+use utils::error::parse_token;
+use utils::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl Machine {
+    fn apply_correction(&self) -> Self {
+        let Machine {
+            button_a,
+            button_b,
+            prize: (px, py),
+        } = *self;
+
+        return Self {
+            button_a,
+            button_b,
+            prize: (px + 10000000000000, py + 10000000000000),
+        };
+    }
+}
+
+/// Shape of a line describing a machine, by the text before each of its coordinates.
+struct LineFormat {
+    x: &'static str,
+    y: &'static str,
+    expected: &'static str,
+}
+
+const FORMATS: [LineFormat; 3] = [
+    LineFormat { x: "Button A: X+", y: ", Y+", expected: "`Button A: X+<x>, Y+<y>`" },
+    LineFormat { x: "Button B: X+", y: ", Y+", expected: "`Button B: X+<x>, Y+<y>`" },
+    LineFormat { x: "Prize: X=", y: ", Y=", expected: "`Prize: X=<x>, Y=<y>`" },
+];
+
+fn read_coordinates(line_num: usize, line: &str, format: &LineFormat) -> Result<(i64, i64), ParseError> {
+    let (x, y) = line.strip_prefix(format.x)
+        .and_then(|coords| coords.split_once(format.y))
+        .ok_or_else(|| ParseError::invalid_token(line_num, 1, line, format.expected))?;
+    let y_column = format.x.len() + x.len() + format.y.len() + 1;
+
+    return Ok((
+        parse_token(line_num, format.x.len() + 1, x, "an integer")?,
+        parse_token(line_num, y_column, y, "an integer")?,
+    ));
+}
+
+/// Reads machines written as three lines each, separated by empty lines.
+fn read_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    return lines.chunks(4)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let first_line = 4 * i + 1;
+            let coordinates = |j: usize| {
+                read_coordinates(first_line + j, chunk.get(j).copied().unwrap_or_default(), &FORMATS[j])
+            };
+
+            if let Some(separator) = chunk.get(3).filter(|line| !line.trim().is_empty()) {
+                return Err(ParseError::invalid_token(first_line + 3, 1, separator, "an empty line"));
+            }
+
+            Ok(Machine { button_a: coordinates(0)?, button_b: coordinates(1)?, prize: coordinates(2)? })
+        })
+        .collect();
+}
+
+fn get_min_tokens(machine: Machine) -> usize {
+    let (ax, ay) = machine.button_a;
+    let (bx, by) = machine.button_b;
+    let (px, py) = machine.prize;
+
+    // Linear algebra ftw
+
+    let det = (ax * by - bx * ay) as f64;
+    let det1 = (px * by - bx * py) as f64;
+    let det2 = (ax * py - px * ay) as f64;
+    let (a_presses, b_presses) = ((det1 / det).round() as i64, (det2 / det).round() as i64);
+    
+    return if verify_solution(machine, a_presses, b_presses) { (3 * a_presses + b_presses) as usize } else { 0 };
+}
+
+fn verify_solution(machine: Machine, a_presses: i64, b_presses: i64) -> bool {
+    let (ax, ay) = machine.button_a;
+    let (bx, by) = machine.button_b;
+
+    return (ax * a_presses + bx * b_presses, ay * a_presses + by * b_presses) == machine.prize;
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_machines(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        let result: usize = machines.iter().cloned()
+            .map(get_min_tokens)
+            .sum();
+
+        result.into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        let result: usize = machines.iter()
+            .map(Machine::apply_correction)
+            .map(get_min_tokens)
+            .sum();
+
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day13>(EXAMPLE), Ok((Answer::Int(480), Answer::Int(875318608908))));
+    }
+
+    #[test]
+    fn malformed_machines_are_reported() {
+        assert_eq!(
+            Day13::parse("Button A: X+94, Y+34\nButton B: X+22 Y+67").unwrap_err(),
+            ParseError::invalid_token(2, 1, "Button B: X+22 Y+67", "`Button B: X+<x>, Y+<y>`"),
+        );
+        assert_eq!(
+            Day13::parse("Button A: X+94, Y+34\nButton B: X+22, Y+6x").unwrap_err(),
+            ParseError::invalid_token(2, 19, "6x", "an integer"),
+        );
+        assert_eq!(
+            Day13::parse("Button A: X+94, Y+34\nButton B: X+22, Y+67").unwrap_err(),
+            ParseError::invalid_token(3, 1, "", "`Prize: X=<x>, Y=<y>`"),
+        );
+        assert_eq!(
+            Day13::parse("Button A: X+1, Y+1\nButton B: X+1, Y+1\nPrize: X=1, Y=1\nButton A: X+1, Y+1").unwrap_err(),
+            ParseError::invalid_token(4, 1, "Button A: X+1, Y+1", "an empty line"),
+        );
+    }
+}
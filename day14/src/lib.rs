@@ -0,0 +1,193 @@
+use std::fs::create_dir_all;
+use std::str::FromStr;
+
+use image::{ImageBuffer, Rgb};
+use utils::{Answer, ParseError, Solution};
+
+pub const WIDTH: i32 = 101;
+pub const HEIGHT: i32 = 103;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn step(&mut self, width: i32, height: i32) {
+        self.x = (self.x + self.vx).rem_euclid(width);
+        self.y = (self.y + self.vy).rem_euclid(height);
+    }
+
+    fn step_after(&mut self, width: i32, height: i32, secs: i32) {
+        self.x = (self.x + self.vx * secs).rem_euclid(width);
+        self.y = (self.y + self.vy * secs).rem_euclid(height);
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseRobotError;
+
+impl FromStr for Robot {
+    type Err = ParseRobotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pos, vel) = s.split_once(' ').ok_or(ParseRobotError)?;
+
+        let (x, y) = pos
+            .strip_prefix("p=")
+            .and_then(|s| s.split_once(','))
+            .ok_or(ParseRobotError)?;
+
+        let (vx, vy) = vel
+            .strip_prefix("v=")
+            .and_then(|s| s.split_once(','))
+            .ok_or(ParseRobotError)?;
+
+        Ok(Self {
+            x: x.parse().map_err(|_| ParseRobotError)?,
+            y: y.parse().map_err(|_| ParseRobotError)?,
+            vx: vx.parse().map_err(|_| ParseRobotError)?,
+            vy: vy.parse().map_err(|_| ParseRobotError)?,
+        })
+    }
+}
+
+pub fn read_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.parse().map_err(|_: ParseRobotError| {
+                ParseError::invalid_token(i + 1, 1, l, "a robot `p=<x>,<y> v=<vx>,<vy>`")
+            })
+        })
+        .collect()
+}
+
+fn get_safety_factor(robots: &[Robot], secs: i32, width: i32, height: i32) -> i32 {
+    let x_middle = width / 2;
+    let y_middle = height / 2;
+
+    let (mut q1, mut q2, mut q3, mut q4) = (0, 0, 0, 0);
+
+    for robot in robots {
+        let mut robot = *robot;
+        robot.step_after(width, height, secs);
+
+        if robot.y < y_middle {
+            if robot.x > x_middle {
+                q1 += 1;
+            } else if robot.x < x_middle {
+                q2 += 1;
+            }
+        } else if robot.y > y_middle {
+            if robot.x < x_middle {
+                q3 += 1;
+            } else if robot.x > x_middle {
+                q4 += 1;
+            }
+        }
+    }
+
+    q1 * q2 * q3 * q4
+}
+
+pub fn print_robots(robots: &[Robot], width: i32, height: i32) {
+    let mut image = ImageBuffer::new(width as u32, height as u32);
+
+    for robot in robots {
+        image[(robot.x as u32, robot.y as u32)] = Rgb([255u8, 255u8, 255u8]);
+    }
+
+    create_dir_all("./img").expect("Failed to open directory");
+    image.save("./img/tree.png").expect("Failed to save image");
+}
+
+fn move_robots(robots: &mut [Robot], width: i32, height: i32) {
+    robots
+        .iter_mut()
+        .for_each(|robot| robot.step(width, height));
+}
+
+fn has_pattern(robots: &[Robot], width: i32, height: i32) -> bool {
+    let mut map = vec![vec![false; width as usize]; height as usize];
+    const LINE_TOLERANCE: i32 = 10;
+
+    for robot in robots {
+        map[robot.y as usize][robot.x as usize] = true;
+    }
+
+    // A priori, no other robot placement will produce a long horizontal line
+    for y in 0..height {
+        let mut count = 0;
+        for x in 0..width {
+            if map[y as usize][x as usize] {
+                count += 1;
+                if count == LINE_TOLERANCE {
+                    return true;
+                }
+            } else {
+                count = 0;
+            }
+        }
+    }
+
+    return false;
+}
+
+pub fn find_tree(robots: &mut [Robot], width: i32, height: i32) -> i32 {
+    let mut secs = 0;
+    while !has_pattern(robots, width, height) {
+        move_robots(robots, width, height);
+        secs += 1;
+    }
+
+    return secs;
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_robots(input)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        get_safety_factor(robots, 100, WIDTH, HEIGHT).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        find_tree(&mut robots.clone(), WIDTH, HEIGHT).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn example_safety_factor() {
+        let robots = read_robots(EXAMPLE).unwrap();
+
+        assert_eq!(get_safety_factor(&robots, 100, 11, 7), 12);
+    }
+}
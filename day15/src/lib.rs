@@ -0,0 +1,336 @@
+use utils::{read_grid, Answer, Direction, Grid, ParseError, Point, Solution};
+
+fn read_map_and_moves(input: &str) -> Result<(Grid<u8>, Vec<Direction>), ParseError> {
+    let (map_str, moves_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::invalid_token(input.lines().count() + 1, 1, "", "an empty line between the map and the moves"))?;
+
+    let mut robots = 0;
+    for (i, line) in map_str.lines().enumerate() {
+        for (j, c) in line.char_indices() {
+            let error = |expected| ParseError::invalid_token(i + 1, j + 1, &c.to_string(), expected);
+
+            match c {
+                '#' | '.' | 'O' => {},
+                '@' if robots > 0 => return Err(error("`#`, `.` or `O`, there being a single robot")),
+                '@' => robots += 1,
+                _ => return Err(error("`#`, `.`, `O` or the robot `@`")),
+            }
+        }
+    }
+    if robots == 0 {
+        return Err(ParseError::invalid_token(map_str.lines().count() + 1, 1, "", "the robot `@`"));
+    }
+
+    let map = read_grid(map_str)?;
+    let moves_start = map.height() + 2;
+
+    let moves = moves_str.lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.char_indices()
+                .map(move |(j, c)| {
+                    u8::try_from(c).ok()
+                        .and_then(Direction::from_arrow)
+                        .ok_or_else(|| ParseError::invalid_token(moves_start + i, j + 1, &c.to_string(), "a move (`^`, `v`, `<` or `>`)"))
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((map, moves))
+}
+
+// fn print_map(map: &Grid<u8>) {
+//     for line in map.rows() {
+//         for &cell in line {
+//             print!("{}", cell as char);
+//         }
+//         println!();
+//     }
+// }
+
+#[derive(Debug)]
+struct RobotNotFoundError;
+
+fn get_robot_pos(map: &Grid<u8>) -> Result<Point, RobotNotFoundError> {
+    map.find(&b'@').ok_or(RobotNotFoundError)
+}
+
+fn execute_move(mut map: Grid<u8>, robot_pos: Point, mv: Direction) -> (Grid<u8>, Point) {
+    let mut robot_pos = robot_pos;
+    let mut next_pos = robot_pos + mv;
+
+    while let Some(cell) = map.get(next_pos) {
+        if cell == &b'#' {
+            return (map, robot_pos);
+        }
+
+        if cell == &b'.' {
+            map[next_pos] = b'O';
+            map[robot_pos] = b'.';
+
+            robot_pos += mv;
+            map[robot_pos] = b'@';
+
+            break;
+        }
+
+        next_pos += mv;
+    }
+
+    return (map, robot_pos);
+}
+
+fn execute_moves(map: Grid<u8>, moves: &[Direction]) -> Result<Grid<u8>, RobotNotFoundError> {
+    let robot_pos = get_robot_pos(&map)?;
+    
+    let (new_map, _) = moves.iter().copied()
+        .fold((map, robot_pos), |(map, robot_pos), mv| execute_move(map, robot_pos, mv));
+
+    Ok(new_map)
+}
+
+fn get_gps_sum(map: &Grid<u8>) -> usize {
+    map.iter()
+        .filter(|(_, &c)| c == b'O' || c == b'[')
+        .map(|(pos, _)| pos.y as usize * 100 + pos.x as usize)
+        .sum()
+}
+
+#[derive(Debug)]
+struct UnrecognizedCellError;
+
+fn to_wide_cell(c: u8) -> Result<[u8; 2], UnrecognizedCellError> {
+    match c {
+        b'#' | b'.' => Ok([c, c]),
+        b'O' => Ok([b'[', b']']),
+        b'@' => Ok([b'@', b'.']),
+        _ => Err(UnrecognizedCellError),
+    }
+}
+
+fn to_wide_map(map: &Grid<u8>) -> Result<Grid<u8>, UnrecognizedCellError> {
+    map.rows()
+        .map(|l| {
+            l.iter().copied()
+                .map(to_wide_cell)
+                .collect::<Result<Vec<_>, _>>()
+                .map(|v| v.into_iter().flatten().collect())
+        })
+        .collect::<Result<_, _>>()
+        .map(Grid::from_rows)
+}
+
+fn move_obstacle_wide(map: &mut Grid<u8>, pos: Point, mv: Direction) -> bool {
+    const LEFT: Direction = Direction::Left;
+    const RIGHT: Direction = Direction::Right;
+
+    match mv {
+        Direction::Up | Direction::Down => {
+            let next_pos = pos + mv;
+            if let (Some(&cell1), Some(&cell2)) = (map.get(next_pos), map.get(next_pos + RIGHT)) {
+                if cell1 == b'#' || cell2 == b'#'
+                    || (cell1 == b'[' && !move_obstacle_wide(map, next_pos, mv))
+                    || (cell1 == b']' && !move_obstacle_wide(map, next_pos + LEFT, mv))
+                    || (cell2 == b'[' && !move_obstacle_wide(map, next_pos + RIGHT, mv)) {
+                    return false;
+                } else {
+                    map[next_pos] = b'[';
+                    map[next_pos + RIGHT] = b']';
+                    map[pos] = b'.';
+                    map[pos + RIGHT] = b'.';
+
+                    return true;
+                }
+            } else {
+                return false;
+            }
+        },
+        Direction::Left => {
+            if let Some(&cell) = map.get(pos + LEFT) {
+                if cell == b'#' {
+                    return false;
+                }
+                if cell == b']' && !move_obstacle_wide(map, pos + LEFT + LEFT, mv) {
+                    return false;
+                }
+                
+                map[pos + LEFT] = b'[';
+                map[pos] = b']';
+                map[pos + RIGHT] = b'.';
+
+                return true;
+            } else {
+                return false;
+            }
+        },
+        Direction::Right => {
+            if let Some(&cell) = map.get(pos + RIGHT + RIGHT) {
+                if cell == b'#' {
+                    return false;
+                }
+                if cell == b'[' && !move_obstacle_wide(map, pos + RIGHT + RIGHT, mv) {
+                    return false;
+                }
+
+                map[pos + RIGHT + RIGHT] = b']';
+                map[pos + RIGHT] = b'[';
+                map[pos] = b'.';
+
+                return true;
+            } else {
+                return false;
+            }
+        },
+    }
+}
+
+fn execute_move_wide(mut map: Grid<u8>, robot_pos: Point, mv: Direction) -> (Grid<u8>, Point) {
+    let mut robot_pos = robot_pos;
+
+    if let Some(&cell) = map.get(robot_pos + mv) {
+        if cell == b'[' {
+            let mut new_map = map.clone();
+            if move_obstacle_wide(&mut new_map, robot_pos + mv, mv) {
+                new_map[robot_pos] = b'.';
+                
+                robot_pos += mv;
+                new_map[robot_pos] = b'@';
+                map = new_map;
+            }
+        } else if cell == b']' {
+            let mut new_map = map.clone();
+            if move_obstacle_wide(&mut new_map, robot_pos + mv + Direction::Left, mv) {
+                new_map[robot_pos] = b'.';
+                
+                robot_pos += mv;
+                new_map[robot_pos] = b'@';
+                map = new_map;
+            }
+        } else if cell == b'.' {
+            map[robot_pos] = b'.';
+                
+            robot_pos += mv;
+            map[robot_pos] = b'@';
+        }
+    }
+
+    return (map, robot_pos);
+}
+
+fn execute_moves_wide(map: Grid<u8>, moves: &[Direction]) -> Result<Grid<u8>, RobotNotFoundError> {
+    let robot_pos = get_robot_pos(&map)?;
+    
+    let (new_map, _) = moves.iter().copied()
+        .fold((map, robot_pos), |(map, robot_pos), mv| execute_move_wide(map, robot_pos, mv));
+
+    Ok(new_map)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<u8>, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_map_and_moves(input)
+    }
+
+    fn part1((map, moves): &Self::Input) -> Answer {
+        let new_map = execute_moves(map.clone(), moves).expect("The robot is checked when parsing");
+
+        get_gps_sum(&new_map).into()
+    }
+
+    fn part2((map, moves): &Self::Input) -> Answer {
+        let wide_map = to_wide_map(map).expect("Cells are checked when parsing");
+        let new_wide_map = execute_moves_wide(wide_map, moves).expect("The robot is checked when parsing");
+
+        get_gps_sum(&new_wide_map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const WIDE_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    #[test]
+    fn small_example() {
+        assert_eq!(solve::<Day15>(SMALL_EXAMPLE), Ok((Answer::Int(2028), Answer::Int(1751))));
+    }
+
+    #[test]
+    fn invalid_maps_are_reported() {
+        assert_eq!(Day15::parse("#..#\n\n<>").unwrap_err(), ParseError::invalid_token(2, 1, "", "the robot `@`"));
+        assert_eq!(
+            Day15::parse("#@.@\n\n<>").unwrap_err(),
+            ParseError::invalid_token(1, 4, "@", "`#`, `.` or `O`, there being a single robot")
+        );
+        assert_eq!(
+            Day15::parse("#@[]\n#.é.\n\n<>").unwrap_err(),
+            ParseError::invalid_token(1, 3, "[", "`#`, `.`, `O` or the robot `@`")
+        );
+        assert_eq!(
+            Day15::parse("#@..\n#.é.\n\n<>").unwrap_err(),
+            ParseError::invalid_token(2, 3, "é", "`#`, `.`, `O` or the robot `@`")
+        );
+    }
+
+    #[test]
+    fn invalid_moves_are_reported() {
+        assert_eq!(
+            Day15::parse("#@.#\n\n<é>").unwrap_err(),
+            ParseError::invalid_token(3, 2, "é", "a move (`^`, `v`, `<` or `>`)")
+        );
+    }
+
+    #[test]
+    fn wide_example() {
+        let (map, moves) = Day15::parse(WIDE_EXAMPLE).unwrap();
+
+        assert_eq!(Day15::part2(&(map, moves)), Answer::Int(618));
+    }
+
+    #[test]
+    fn move_obstacle_wide_pushes_stacked_boxes() {
+        let mut map = read_grid("########\n#......#\n#..[]..#\n#.[][].#\n#......#\n########").unwrap();
+
+        assert!(move_obstacle_wide(&mut map, Point::new(4, 3), Direction::Up));
+        assert_eq!(map, read_grid("########\n#..[]..#\n#...[].#\n#.[]...#\n#......#\n########").unwrap());
+    }
+
+    #[test]
+    fn move_obstacle_wide_stops_at_walls() {
+        let mut map = read_grid("########\n#...#..#\n#..[]..#\n#.[]...#\n########").unwrap();
+
+        assert!(!move_obstacle_wide(&mut map, Point::new(2, 3), Direction::Up));
+        assert!(move_obstacle_wide(&mut map, Point::new(2, 3), Direction::Left));
+        assert!(!move_obstacle_wide(&mut map, Point::new(1, 3), Direction::Left));
+        assert!(move_obstacle_wide(&mut map, Point::new(3, 2), Direction::Right));
+        assert_eq!(map.rows().nth(2), Some(&b"#...[].#"[..]));
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use utils::graph::{dijkstra, ShortestPaths};
+use utils::{read_grid, Answer, Direction, Grid, ParseError, Point, Solution};
+
+/// Reads the maze, which holds walls, open tiles, a single start `S` and a single end `E`.
+fn read_maze(input: &str) -> Result<Grid<u8>, ParseError> {
+    let (mut starts, mut ends) = (0, 0);
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.char_indices() {
+            let error = |expected| ParseError::invalid_token(i + 1, j + 1, &c.to_string(), expected);
+
+            match c {
+                '#' | '.' => {},
+                'S' if starts > 0 => return Err(error("`#`, `.` or `E`, there being a single start")),
+                'S' => starts += 1,
+                'E' if ends > 0 => return Err(error("`#`, `.` or `S`, there being a single end")),
+                'E' => ends += 1,
+                _ => return Err(error("`#`, `.`, the start `S` or the end `E`")),
+            }
+        }
+    }
+
+    let missing = |expected| ParseError::invalid_token(input.lines().count() + 1, 1, "", expected);
+    if starts == 0 {
+        return Err(missing("the start `S`"));
+    }
+    if ends == 0 {
+        return Err(missing("the end `E`"));
+    }
+
+    return read_grid(input);
+}
+
+fn get_start(map: &Grid<u8>) -> Option<Point> {
+    map.find(&b'S')
+}
+
+fn get_end(map: &Grid<u8>) -> Option<Point> {
+    map.find(&b'E')
+}
+
+#[derive(Debug)]
+struct  StartNotFoundError;
+
+type Reindeer = (Point, Direction);
+
+fn get_moves(map: &Grid<u8>, (pos, dir): Reindeer) -> impl Iterator<Item = (Reindeer, usize)> {
+    let forward = map.get(pos + dir)
+        .filter(|&&c| c != b'#')
+        .map(|_| ((pos + dir, dir), 1));
+
+    return forward.into_iter()
+        .chain([((pos, dir.rotate_left()), 1000), ((pos, dir.rotate_right()), 1000)]);
+}
+
+fn get_paths(map: &Grid<u8>) -> Result<ShortestPaths<Reindeer>, StartNotFoundError> {
+    let start = get_start(map).ok_or(StartNotFoundError)?;
+
+    Ok(dijkstra([(start, Direction::Right)], |reindeer| get_moves(map, reindeer)))
+}
+
+/// Facings the end can be reached with at the lowest score, along with that score.
+fn get_best_ends(paths: &ShortestPaths<Reindeer>, end: Point) -> (usize, Vec<Reindeer>) {
+    let end_score = Direction::ALL.into_iter()
+        .filter_map(|dir| paths.dist((end, dir)))
+        .min()
+        .unwrap_or(usize::MAX);
+
+    let ends = Direction::ALL.into_iter()
+        .map(|dir| (end, dir))
+        .filter(|&reindeer| paths.dist(reindeer) == Some(end_score))
+        .collect();
+
+    return (end_score, ends);
+}
+
+fn count_best_tiles(paths: &ShortestPaths<Reindeer>, ends: Vec<Reindeer>) -> usize {
+    return paths.nodes_on_paths(ends).into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len();
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_maze(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let end = get_end(map).expect("The end is checked when parsing");
+        let paths = get_paths(map).expect("The start is checked when parsing");
+        let (score, _) = get_best_ends(&paths, end);
+
+        score.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let end = get_end(map).expect("The end is checked when parsing");
+        let paths = get_paths(map).expect("The start is checked when parsing");
+        let (_, ends) = get_best_ends(&paths, end);
+
+        count_best_tiles(&paths, ends).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::solution::solve;
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve::<Day16>(EXAMPLE), Ok((Answer::Int(7036), Answer::Int(45))));
+    }
+    #[test]
+    fn second_example() {
+        assert_eq!(solve::<Day16>(SECOND_EXAMPLE), Ok((Answer::Int(11048), Answer::Int(64))));
+    }
+
+    #[test]
+    fn invalid_mazes_are_reported() {
+        assert_eq!(Day16::parse("#S.#\n#..#").unwrap_err(), ParseError::invalid_token(3, 1, "", "the end `E`"));
+        assert_eq!(Day16::parse("#..#\n#.E#").unwrap_err(), ParseError::invalid_token(3, 1, "", "the start `S`"));
+        assert_eq!(
+            Day16::parse("#SE#\n#.E#").unwrap_err(),
+            ParseError::invalid_token(2, 3, "E", "`#`, `.` or `S`, there being a single end")
+        );
+        assert_eq!(
+            Day16::parse("#SE#\n#.é#").unwrap_err(),
+            ParseError::invalid_token(2, 3, "é", "`#`, `.`, the start `S` or the end `E`")
+        );
+    }
+}
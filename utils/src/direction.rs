@@ -0,0 +1,83 @@
+use crate::point::Point;
+
+/// One of the four orthogonal directions on a map, listed clockwise from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Position of the direction in `ALL`, handy to index per-direction arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn rotate_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Parses an arrow, one of `^`, `>`, `v` or `<`.
+    pub fn from_arrow(byte: u8) -> Option<Self> {
+        match byte {
+            b'^' => Some(Direction::Up),
+            b'>' => Some(Direction::Right),
+            b'v' => Some(Direction::Down),
+            b'<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> u8 {
+        b"^>v<"[self.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations_are_consistent() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.rotate_right().rotate_left(), dir);
+            assert_eq!(dir.rotate_right().rotate_right(), dir.reverse());
+            assert_eq!(dir.offset() + dir.reverse().offset(), Point::ORIGIN);
+        }
+
+        assert_eq!(Direction::Up.rotate_right(), Direction::Right);
+        assert_eq!(Direction::Up.rotate_left(), Direction::Left);
+    }
+
+    #[test]
+    fn parses_arrows() {
+        for dir in Direction::ALL {
+            assert_eq!(Direction::from_arrow(dir.to_arrow()), Some(dir));
+        }
+
+        assert_eq!(Direction::from_arrow(b'x'), None);
+    }
+}
@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// Error found while parsing puzzle input, pointing to where the input went wrong.
+///
+/// Lines and columns are 1-based, as in any text editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The text at the position is not what was expected, e.g. an integer.
+    InvalidToken { expected: &'static str },
+    /// The line holds the wrong number of items, e.g. integers or columns.
+    WrongCount { item: &'static str, expected: usize, found: usize },
+    /// The line is well-formed but inconsistent with the rest of the input.
+    Invalid { reason: String },
+}
+
+impl ParseError {
+    pub fn invalid_token(line: usize, column: usize, text: &str, expected: &'static str) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            kind: ParseErrorKind::InvalidToken { expected },
+        }
+    }
+
+    pub fn wrong_count(line: usize, text: &str, item: &'static str, expected: usize, found: usize) -> Self {
+        Self {
+            line,
+            column: 1,
+            text: text.to_string(),
+            kind: ParseErrorKind::WrongCount { item, expected, found },
+        }
+    }
+
+    pub fn invalid(line: usize, text: &str, reason: String) -> Self {
+        Self {
+            line,
+            column: 1,
+            text: text.to_string(),
+            kind: ParseErrorKind::Invalid { reason },
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::InvalidToken { expected } if self.text.is_empty() => {
+                write!(f, "line {}, column {}: expected {expected}, found end of input", self.line, self.column)
+            },
+            ParseErrorKind::InvalidToken { expected } => {
+                write!(f, "line {}, column {}: expected {expected}, found `{}`", self.line, self.column, self.text)
+            },
+            ParseErrorKind::WrongCount { item, expected, found } => {
+                write!(f, "line {}: expected {expected} {item}, found {found}", self.line)
+            },
+            ParseErrorKind::Invalid { reason } => write!(f, "line {}: {reason}", self.line),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error found while loading the input of a binary: either it could not be read, or it did not
+/// parse.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "Failed to read input: {err}"),
+            InputError::Parse(err) => write!(f, "Failed to parse input: {err}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(err) => Some(err),
+            InputError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> Self {
+        InputError::Parse(err)
+    }
+}
+
+/// Splits a line into whitespace-separated tokens, along with the column each starts in.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize + 1, token))
+}
+
+pub fn parse_token<T: FromStr>(line: usize, column: usize, token: &str, expected: &'static str) -> Result<T, ParseError> {
+    token.parse()
+        .map_err(|_| ParseError::invalid_token(line, column, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_report_their_columns() {
+        let found: Vec<_> = tokens("  12 abc   3").collect();
+
+        assert_eq!(found, vec![(3, "12"), (6, "abc"), (12, "3")]);
+    }
+
+    #[test]
+    fn errors_point_to_the_token() {
+        let err = parse_token::<i32>(4, 6, "x1", "an integer").unwrap_err();
+
+        assert_eq!(err.to_string(), "line 4, column 6: expected an integer, found `x1`");
+        assert_eq!(ParseError::invalid_token(2, 1, "", "a digit").to_string(), "line 2, column 1: expected a digit, found end of input");
+        assert_eq!(ParseError::wrong_count(3, "1 2 3", "integers", 2, 3).to_string(), "line 3: expected 2 integers, found 3");
+        assert_eq!(ParseError::invalid(5, "1,2", "pages out of range".to_string()).to_string(), "line 5: pages out of range");
+    }
+}
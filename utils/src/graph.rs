@@ -0,0 +1,343 @@
+//! Generic graph algorithms.
+//!
+//! Graphs are never built explicitly: every algorithm takes a closure listing the neighbors
+//! of a node, so grids, rule lists or state spaces can be searched as they are.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Distance, in edges, from `start` to every node reachable from it.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(N) -> I) -> HashMap<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut dists = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let dist = dists[&node];
+
+        for adj in neighbors(node) {
+            if let Entry::Vacant(entry) = dists.entry(adj) {
+                entry.insert(dist + 1);
+                queue.push_back(adj);
+            }
+        }
+    }
+
+    return dists;
+}
+
+/// Splits the nodes into connected components, each listed in BFS order from its first node.
+///
+/// The neighbor relation is expected to be symmetric, as in an undirected graph.
+pub fn connected_components<N, I>(nodes: impl IntoIterator<Item = N>, mut neighbors: impl FnMut(N) -> I) -> Vec<Vec<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut components = vec![];
+
+    for root in nodes {
+        if !visited.insert(root) {
+            continue;
+        }
+
+        let mut component = vec![root];
+        let mut next = 0;
+
+        while let Some(&node) = component.get(next) {
+            next += 1;
+
+            for adj in neighbors(node) {
+                if visited.insert(adj) {
+                    component.push(adj);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    return components;
+}
+
+/// Number of distinct paths from `start` to any node satisfying `is_end`, which stop there.
+///
+/// The graph reachable from `start` must be acyclic.
+pub fn count_paths<N, I>(start: N, mut is_end: impl FnMut(N) -> bool, mut neighbors: impl FnMut(N) -> I) -> usize
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count_aux<N, I>(
+        node: N,
+        is_end: &mut impl FnMut(N) -> bool,
+        neighbors: &mut impl FnMut(N) -> I,
+        cache: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Copy + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        if is_end(node) {
+            return 1;
+        }
+
+        if let Some(&count) = cache.get(&node) {
+            return count;
+        }
+
+        let adjs: Vec<N> = neighbors(node).into_iter().collect();
+        let count = adjs.into_iter()
+            .map(|adj| count_aux(adj, is_end, neighbors, cache))
+            .sum();
+        cache.insert(node, count);
+
+        return count;
+    }
+
+    return count_aux(start, &mut is_end, &mut neighbors, &mut HashMap::new());
+}
+
+/// Result of Dijkstra's algorithm, keeping every predecessor on a shortest path.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    dists: HashMap<N, usize>,
+    preds: HashMap<N, Vec<N>>,
+}
+
+impl<N: Copy + Eq + Hash> ShortestPaths<N> {
+    /// Cost of the shortest path to `node`, if it is reachable.
+    pub fn dist(&self, node: N) -> Option<usize> {
+        self.dists.get(&node).copied()
+    }
+
+    /// Nodes right before `node` on any of its shortest paths.
+    pub fn preds(&self, node: N) -> &[N] {
+        self.preds.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every node lying on a shortest path to one of `ends`, the ends included.
+    pub fn nodes_on_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes: HashSet<N> = ends.into_iter()
+            .filter(|end| self.dists.contains_key(end))
+            .collect();
+        let mut stack: Vec<N> = nodes.iter().copied().collect();
+
+        while let Some(node) = stack.pop() {
+            for &pred in self.preds(node) {
+                if nodes.insert(pred) {
+                    stack.push(pred);
+                }
+            }
+        }
+
+        return nodes;
+    }
+}
+
+/// Shortest paths from any of `starts`, with `neighbors` listing each adjacent node along with
+/// the cost of the edge to it.
+pub fn dijkstra<N, I>(starts: impl IntoIterator<Item = N>, mut neighbors: impl FnMut(N) -> I) -> ShortestPaths<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut dists = HashMap::new();
+    let mut preds: HashMap<N, Vec<N>> = HashMap::new();
+
+    // Nodes are stored aside, so that the heap does not need them to be ordered
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        dists.insert(start, 0);
+        heap.push(Reverse((0, queued.len())));
+        queued.push(start);
+    }
+
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        let node = queued[i];
+        if cost > dists[&node] {
+            continue;
+        }
+
+        for (adj, weight) in neighbors(node) {
+            let adj_cost = cost + weight;
+
+            match dists.get(&adj) {
+                Some(&dist) if adj_cost > dist => {},
+                Some(&dist) if adj_cost == dist => preds.entry(adj).or_default().push(node),
+                _ => {
+                    dists.insert(adj, adj_cost);
+                    preds.insert(adj, vec![node]);
+                    heap.push(Reverse((adj_cost, queued.len())));
+                    queued.push(adj);
+                },
+            }
+        }
+    }
+
+    return ShortestPaths { dists, preds };
+}
+
+/// Cycle found while sorting a graph, each node having an edge to the next one and the last
+/// node having an edge back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+/// Orders the nodes so that every edge goes forward, or finds a cycle preventing it.
+///
+/// Edges to nodes not in `nodes` are ignored, and nodes listed several times are sorted once.
+/// Among the valid orders, nodes are kept as close as possible to their order in `nodes`. The
+/// cycle found only depends on that order too.
+pub fn topological_sort<N, I>(nodes: &[N], mut neighbors: impl FnMut(N) -> I) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let nodes: Vec<N> = nodes.iter().copied().filter(|&node| seen.insert(node)).collect();
+
+    let mut indegrees: HashMap<N, usize> = nodes.iter().map(|&node| (node, 0)).collect();
+    let mut adjs: HashMap<N, Vec<N>> = HashMap::new();
+
+    for &node in &nodes {
+        let node_adjs: Vec<N> = neighbors(node).into_iter()
+            .filter(|adj| indegrees.contains_key(adj))
+            .collect();
+
+        for adj in &node_adjs {
+            *indegrees.get_mut(adj).unwrap() += 1;
+        }
+        adjs.insert(node, node_adjs);
+    }
+
+    let mut queue: VecDeque<N> = nodes.iter()
+        .copied()
+        .filter(|node| indegrees[node] == 0)
+        .collect();
+
+    let mut sorted = vec![];
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node);
+
+        for adj in &adjs[&node] {
+            let indegree = indegrees.get_mut(adj).unwrap();
+            *indegree -= 1;
+            if *indegree == 0 {
+                queue.push_back(*adj);
+            }
+        }
+    }
+
+    if sorted.len() == indegrees.len() {
+        return Ok(sorted);
+    }
+
+    // Every node left has a predecessor left too, so walking back through them must loop.
+    // The first predecessor in `nodes` order is taken, so that the cycle does not change.
+    let mut preds: HashMap<N, N> = HashMap::new();
+    for node in nodes.iter().filter(|node| indegrees[node] > 0) {
+        for &adj in &adjs[node] {
+            if indegrees[&adj] > 0 {
+                preds.entry(adj).or_insert(*node);
+            }
+        }
+    }
+
+    let mut walk = vec![];
+    let mut seen = HashSet::new();
+    let mut node = *nodes.iter().find(|node| indegrees[node] > 0).unwrap();
+
+    while seen.insert(node) {
+        walk.push(node);
+        node = preds[&node];
+    }
+
+    let loop_start = walk.iter().position(|&walked| walked == node).unwrap();
+    let mut cycle = walk.split_off(loop_start);
+    cycle.reverse();
+
+    return Err(Cycle(cycle));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_graph(node: i32) -> Vec<i32> {
+        if node < 5 { vec![node + 1] } else { vec![] }
+    }
+
+    #[test]
+    fn bfs_measures_edges() {
+        let dists = bfs(0, line_graph);
+
+        assert_eq!(dists.len(), 6);
+        assert_eq!(dists[&5], 5);
+    }
+
+    #[test]
+    fn components_group_connected_nodes() {
+        let neighbors = |node: i32| [node - 1, node + 1].into_iter().filter(move |adj| adj / 10 == node / 10 && *adj >= 0);
+        let components = connected_components([1, 12, 3, 15], neighbors);
+
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].len(), 10);
+    }
+
+    #[test]
+    fn count_paths_in_a_diamond() {
+        let edges = HashMap::from([(0, vec![1, 2]), (1, vec![3]), (2, vec![3]), (3, vec![4, 5])]);
+        let neighbors = |node| edges.get(&node).cloned().unwrap_or_default();
+
+        assert_eq!(count_paths(0, |node| node == 4, neighbors), 2);
+        assert_eq!(count_paths(0, |node| node >= 4, neighbors), 4);
+    }
+
+    #[test]
+    fn dijkstra_keeps_every_shortest_path() {
+        let edges = HashMap::from([(0, vec![(1, 1), (2, 1), (3, 5)]), (1, vec![(3, 1)]), (2, vec![(3, 1)])]);
+        let paths = dijkstra([0], |node| edges.get(&node).cloned().unwrap_or_default());
+
+        assert_eq!(paths.dist(3), Some(2));
+        assert_eq!(paths.preds(3).len(), 2);
+        assert_eq!(paths.nodes_on_paths([3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(paths.dist(4), None);
+    }
+
+    #[test]
+    fn topological_sort_finds_order_or_cycle() {
+        assert_eq!(topological_sort(&[3, 1, 2], |node| vec![node + 1]), Ok(vec![1, 2, 3]));
+
+        let edges = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![4]), (4, vec![2])]);
+        let result = topological_sort(&[1, 2, 3, 4], |node| edges[&node].clone());
+        let Err(Cycle(mut cycle)) = result else { panic!("Cycle not found") };
+
+        let first = cycle.iter().position(|&node| node == 2).unwrap();
+        cycle.rotate_left(first);
+        assert_eq!(cycle, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn topological_sort_keeps_duplicates_once() {
+        assert_eq!(topological_sort(&[3, 3, 1, 2, 1], |node| vec![node + 1]), Ok(vec![1, 2, 3]));
+        assert_eq!(topological_sort(&[3, 3], |_| vec![]), Ok(vec![3]));
+    }
+
+    #[test]
+    fn topological_sort_reports_overlapping_cycles_deterministically() {
+        let edges = HashMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![1])]);
+
+        for _ in 0..20 {
+            assert_eq!(topological_sort(&[1, 2, 3], |node| edges[&node].clone()), Err(Cycle(vec![2, 1])));
+        }
+        assert_eq!(topological_sort(&[3, 2, 1], |node| edges[&node].clone()), Err(Cycle(vec![1, 2, 3])));
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::{Index, IndexMut};
+
+use crate::direction::Direction;
+use crate::point::Point;
+
+pub(crate) const DIAGONALS: [Point; 4] = [Point::new(1, 1), Point::new(-1, 1), Point::new(-1, -1), Point::new(1, -1)];
+
+/// Rectangular grid of cells, stored row by row in a single vector.
+///
+/// Cells are addressed by signed points, so that stepping off the grid can be checked
+/// with `get` instead of guarding every subtraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid cells do not match its dimensions");
+
+        Self { width, height, cells }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let (width, height) = (rows.first().map_or(0, Vec::len), rows.len());
+        assert!(rows.iter().all(|row| row.len() == width), "Grid rows have different lengths");
+
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Point) -> bool {
+        0 <= pos.x && (pos.x as usize) < self.width && 0 <= pos.y && (pos.y as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        self.in_bounds(pos).then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Iterates over every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.width * self.height).map(move |i| Point::new((i % width) as i32, (i / width) as i32))
+    }
+
+    /// Iterates over every cell of the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Orthogonal neighbors of a position that lie inside the grid.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|&adj| self.in_bounds(adj))
+    }
+
+    /// Orthogonal and diagonal neighbors of a position that lie inside the grid.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter()
+            .map(Direction::offset)
+            .chain(DIAGONALS)
+            .map(move |offset| pos + offset)
+            .filter(|&adj| self.in_bounds(adj))
+    }
+
+    /// Position of the first cell, row by row, equal to the given value.
+    pub fn find(&self, value: &T) -> Option<Point> where T: PartialEq {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// New grid with the same dimensions, with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Self::from_rows(rows)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("Position {pos:?} is out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {pos:?} is out of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let grid = Grid::filled(3, 2, 0);
+
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn cells_are_stored_row_by_row() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid[Point::new(2, 0)], 3);
+        assert_eq!(grid.find(&4), Some(Point::new(0, 1)));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    }
+}
@@ -1,60 +1,130 @@
-use std::io::{self, BufRead, Read};
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
 
-pub fn read_two_lists() -> (Vec<i32>, Vec<i32>) {
+pub mod direction;
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod point;
+pub mod search;
+pub mod solution;
+
+pub use direction::Direction;
+pub use error::{InputError, ParseError, ParseErrorKind};
+pub use grid::Grid;
+pub use point::Point;
+pub use solution::{Answer, Solution};
+
+use error::{parse_token, tokens};
+
+pub fn read_two_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
     let (mut list1, mut list2) = (vec![], vec![]);
 
-    let stdin = io::stdin();
-    let lines = stdin.lock().lines().map(|s| s.unwrap());
-    for line in lines {
-        let nums: Vec<i32> = line.trim()
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
+    for (i, line) in input.lines().enumerate() {
+        let words: Vec<(usize, &str)> = tokens(line).collect();
+        if words.len() != 2 {
+            return Err(ParseError::wrong_count(i + 1, line, "integers", 2, words.len()));
+        }
+
+        let nums = words.into_iter()
+            .map(|(column, word)| parse_token(i + 1, column, word, "an integer"))
+            .collect::<Result<Vec<i32>, _>>()?;
 
         list1.push(nums[0]);
         list2.push(nums[1]);
     }
 
-    return (list1, list2);
+    return Ok((list1, list2));
 }
 
-pub fn read_int_lists() -> Vec<Vec<i32>> {
-    let stdin = io::stdin();
-    let lines = stdin.lock().lines().map(|line| line.unwrap());
-
-    let lists: Vec<Vec<i32>> = lines.map(|line| {
-        line.split_whitespace()
-            .map(|word| word.parse().unwrap())
+pub fn read_int_lists(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let lists = input.lines().enumerate().map(|(i, line)| {
+        tokens(line)
+            .map(|(column, word)| parse_token(i + 1, column, word, "an integer"))
             .collect()
     }).collect();
 
     return lists;
 }
 
-pub fn read_all() -> String {
-    let stdin = io::stdin();
+/// Reads the whole puzzle input from any source, to be handed to the parsers as a `&str`.
+pub fn read_input(mut reader: impl Read) -> io::Result<String> {
     let mut text = String::new();
 
-    stdin.lock().read_to_string(&mut text)
-        .expect("Failed to read stdio");
+    reader.read_to_string(&mut text)?;
+
+    return Ok(text);
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> io::Result<String> {
+    return read_input(fs::File::open(path)?);
+}
 
-    return text;
+pub fn read_all() -> io::Result<String> {
+    return read_input(io::stdin().lock());
 }
 
-pub fn read_byte_matrix() -> Vec<Vec<u8>> {
-    let stdin = io::stdin();
-    let lines = stdin.lock().lines()
-        .map(|line| line.expect("Failed to read line"));
+/// Reads the input of a binary from the file at `path`, or from stdin if there is none, and
+/// parses it.
+pub fn load_input<T>(path: Option<&str>, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, InputError> {
+    let input = match path {
+        Some(path) => read_file(path)?,
+        None => read_all()?,
+    };
 
-    let matrix = lines
-        .map(String::into_bytes)
+    return Ok(parse(&input)?);
+}
+
+pub fn read_byte_matrix(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let matrix: Vec<Vec<u8>> = input.lines()
+        .map(|line| line.as_bytes().to_vec())
         .collect();
 
-    return matrix;
+    if let Some(first) = matrix.first() {
+        let width = first.len();
+        let ragged_row = matrix.iter().position(|row| row.len() != width);
+
+        if let Some(i) = ragged_row {
+            let line = input.lines().nth(i).unwrap_or_default();
+            return Err(ParseError::wrong_count(i + 1, line, "columns", width, matrix[i].len()));
+        }
+    }
+
+    return Ok(matrix);
+}
+
+pub fn read_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    return read_byte_matrix(input).map(Grid::from_rows);
 }
 
-pub fn read_usize_list() -> Vec<usize> {
-    return read_all().split_whitespace()
-        .map(|w| w.parse().unwrap())
+pub fn read_usize_list(input: &str) -> Result<Vec<usize>, ParseError> {
+    return input.lines().enumerate()
+        .flat_map(|(i, line)| tokens(line).map(move |(column, word)| (i + 1, column, word)))
+        .map(|(line, column, word)| parse_token(line, column, word, "a non-negative integer"))
         .collect();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readers_report_the_failing_line() {
+        assert_eq!(read_two_lists("1 2\n3 4"), Ok((vec![1, 3], vec![2, 4])));
+        assert_eq!(read_two_lists("1 2\n3"), Err(ParseError::wrong_count(2, "3", "integers", 2, 1)));
+        assert_eq!(read_int_lists("1 2\n3 x"), Err(ParseError::invalid_token(2, 3, "x", "an integer")));
+        assert_eq!(read_usize_list("1 -2"), Err(ParseError::invalid_token(1, 3, "-2", "a non-negative integer")));
+    }
+
+    #[test]
+    fn byte_matrix_must_be_rectangular() {
+        assert_eq!(read_byte_matrix("ab\ncd"), Ok(vec![b"ab".to_vec(), b"cd".to_vec()]));
+        assert_eq!(read_byte_matrix("ab\nc"), Err(ParseError::wrong_count(2, "c", "columns", 2, 1)));
+    }
+
+    #[test]
+    fn input_can_be_read_from_memory() {
+        assert_eq!(read_input("3   4\n".as_bytes()).unwrap(), "3   4\n");
+    }
+}
@@ -0,0 +1,227 @@
+use crate::direction::Direction;
+use crate::error::ParseError;
+use crate::grid::{Grid, DIAGONALS};
+use crate::point::Point;
+
+/// Directions words may be read in, out of the 8 of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directions {
+    Orthogonal,
+    Diagonal,
+    All,
+}
+
+impl Directions {
+    /// Step taken between two consecutive letters, for every direction considered.
+    pub fn steps(self) -> Vec<Point> {
+        match self {
+            Directions::Orthogonal => Direction::ALL.map(Direction::offset).to_vec(),
+            Directions::Diagonal => DIAGONALS.to_vec(),
+            Directions::All => [Direction::ALL.map(Direction::offset), DIAGONALS].concat(),
+        }
+    }
+}
+
+/// Word found in a grid, by its index in the searched list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordMatch {
+    pub word: usize,
+    /// Position of the first letter
+    pub start: Point,
+    /// Step from one letter to the next
+    pub step: Point,
+}
+
+impl WordMatch {
+    /// Position of the `i`-th letter of the word.
+    pub fn letter(&self, i: usize) -> Point {
+        self.start + self.step * i as i32
+    }
+}
+
+/// Finds every occurrence of the words read in a straight line along the given directions.
+///
+/// Palindromes are found once per direction they can be read in, e.g. both forwards and backwards.
+pub fn find_words(grid: &Grid<u8>, words: &[&str], directions: Directions) -> Vec<WordMatch> {
+    let steps = directions.steps();
+    let mut matches = vec![];
+
+    for start in grid.positions() {
+        for (word, text) in words.iter().enumerate() {
+            if text.as_bytes().first() != grid.get(start) {
+                continue;
+            }
+
+            for &step in &steps {
+                let found = WordMatch { word, start, step };
+                let spelled = text.bytes()
+                    .enumerate()
+                    .all(|(i, letter)| grid.get(found.letter(i)) == Some(&letter));
+
+                if spelled {
+                    matches.push(found);
+                }
+            }
+        }
+    }
+
+    return matches;
+}
+
+/// One of the 8 symmetries of a square: a number of quarter turns clockwise, optionally
+/// applied after mirroring left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symmetry {
+    pub mirrored: bool,
+    pub quarter_turns: u8,
+}
+
+impl Symmetry {
+    pub const IDENTITY: Symmetry = Symmetry { mirrored: false, quarter_turns: 0 };
+
+    pub const ROTATIONS: [Symmetry; 4] = [
+        Symmetry { mirrored: false, quarter_turns: 0 },
+        Symmetry { mirrored: false, quarter_turns: 1 },
+        Symmetry { mirrored: false, quarter_turns: 2 },
+        Symmetry { mirrored: false, quarter_turns: 3 },
+    ];
+
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry { mirrored: false, quarter_turns: 0 },
+        Symmetry { mirrored: false, quarter_turns: 1 },
+        Symmetry { mirrored: false, quarter_turns: 2 },
+        Symmetry { mirrored: false, quarter_turns: 3 },
+        Symmetry { mirrored: true, quarter_turns: 0 },
+        Symmetry { mirrored: true, quarter_turns: 1 },
+        Symmetry { mirrored: true, quarter_turns: 2 },
+        Symmetry { mirrored: true, quarter_turns: 3 },
+    ];
+}
+
+/// Rectangular 2D pattern, in which `.` cells match anything.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stencil {
+    width: usize,
+    height: usize,
+    /// Cells that must match, by offset from the top-left corner, sorted
+    cells: Vec<(Point, u8)>,
+}
+
+impl Stencil {
+    pub const WILDCARD: u8 = b'.';
+
+    /// Parses a stencil written row by row, rows being separated by `/` or line breaks,
+    /// e.g. `M.S / .A. / M.S`. Whitespace around rows and blank rows are ignored.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let (line_nums, rows): (Vec<usize>, Vec<&str>) = text.lines()
+            .enumerate()
+            .flat_map(|(i, line)| line.split('/').map(move |row| (i + 1, row.trim())))
+            .filter(|(_, row)| !row.is_empty())
+            .unzip();
+
+        let width = rows.first()
+            .ok_or_else(|| ParseError::invalid_token(1, 1, "", "a stencil row"))?
+            .len();
+
+        if let Some(i) = rows.iter().position(|row| row.len() != width) {
+            return Err(ParseError::wrong_count(line_nums[i], rows[i], "columns", width, rows[i].len()));
+        }
+
+        let mut cells: Vec<(Point, u8)> = rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.bytes().enumerate().map(move |(x, c)| (Point::new(x as i32, y as i32), c)))
+            .filter(|&(_, c)| c != Self::WILDCARD)
+            .collect();
+        cells.sort_unstable();
+
+        return Ok(Self { width, height: rows.len(), cells });
+    }
+
+    pub fn transformed(&self, symmetry: Symmetry) -> Self {
+        let (mut width, mut height) = (self.width as i32, self.height as i32);
+        let mut cells = self.cells.clone();
+
+        if symmetry.mirrored {
+            cells.iter_mut().for_each(|(pos, _)| pos.x = width - 1 - pos.x);
+        }
+
+        for _ in 0..symmetry.quarter_turns % 4 {
+            cells.iter_mut().for_each(|(pos, _)| *pos = Point::new(height - 1 - pos.y, pos.x));
+            (width, height) = (height, width);
+        }
+
+        cells.sort_unstable();
+        return Self { width: width as usize, height: height as usize, cells };
+    }
+
+    /// Distinct stencils obtained by the symmetries, as symmetric stencils look the same under
+    /// several of them.
+    pub fn variants(&self, symmetries: &[Symmetry]) -> Vec<Stencil> {
+        let mut variants: Vec<Stencil> = vec![];
+
+        for &symmetry in symmetries {
+            let variant = self.transformed(symmetry);
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        }
+
+        return variants;
+    }
+
+    /// Whether the stencil matches with its top-left corner at `corner`, fitting in the grid.
+    pub fn matches_at(&self, grid: &Grid<u8>, corner: Point) -> bool {
+        let far_corner = corner + Point::new(self.width as i32 - 1, self.height as i32 - 1);
+
+        return grid.in_bounds(corner) && grid.in_bounds(far_corner)
+            && self.cells.iter().all(|&(offset, c)| grid[corner + offset] == c);
+    }
+
+    /// Counts placements of the stencil under any of the symmetries, each placement once.
+    pub fn count(&self, grid: &Grid<u8>, symmetries: &[Symmetry]) -> usize {
+        return self.variants(symmetries).iter()
+            .map(|variant| grid.positions().filter(|&corner| variant.matches_at(grid, corner)).count())
+            .sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_words_along_chosen_directions() {
+        let grid = Grid::from_rows(vec![b"CAT".to_vec(), b"AAX".to_vec(), b"TXT".to_vec()]);
+
+        let all = find_words(&grid, &["CAT", "AX"], Directions::All);
+        assert_eq!(all.iter().filter(|found| found.word == 0).count(), 3);
+        assert_eq!(all.iter().filter(|found| found.word == 1).count(), 4);
+
+        let diagonal = find_words(&grid, &["CAT"], Directions::Diagonal);
+        assert_eq!(diagonal, vec![WordMatch { word: 0, start: Point::new(0, 0), step: Point::new(1, 1) }]);
+        assert_eq!(diagonal[0].letter(2), Point::new(2, 2));
+
+        assert_eq!(find_words(&grid, &["CAT"], Directions::Orthogonal).len(), 2);
+    }
+
+    #[test]
+    fn symmetric_stencils_are_counted_once() {
+        let x_mas = Stencil::parse("M.S / .A. / M.S").unwrap();
+        assert_eq!(x_mas.variants(&Symmetry::ALL).len(), 4);
+
+        let plus = Stencil::parse(".A.\nAAA\n.A.").unwrap();
+        assert_eq!(plus.variants(&Symmetry::ALL).len(), 1);
+    }
+
+    #[test]
+    fn stencils_of_any_size() {
+        let grid = Grid::from_rows(vec![b"ABCD".to_vec(), b"EFGH".to_vec()]);
+        let stencil = Stencil::parse("AB.D").unwrap();
+
+        assert_eq!(stencil.count(&grid, &[Symmetry::IDENTITY]), 1);
+        assert_eq!(Stencil::parse("DHGF/C..B/A..E").unwrap().count(&grid, &Symmetry::ALL), 0);
+        assert_eq!(Stencil::parse("EA/FB/GC/HD").unwrap().count(&grid, &Symmetry::ROTATIONS), 1);
+        assert_eq!(Stencil::parse("AB/CD/E"), Err(ParseError::wrong_count(1, "E", "columns", 2, 1)));
+        assert_eq!(Stencil::parse("\nAB\n\nCD / E\n"), Err(ParseError::wrong_count(4, "E", "columns", 2, 1)));
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+use crate::error::ParseError;
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(num) => write!(f, "{num}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(num: i32) -> Self {
+        Answer::Int(num as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(num: i64) -> Self {
+        Answer::Int(num)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(num: usize) -> Self {
+        i64::try_from(num)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(num.to_string()))
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(num: u128) -> Self {
+        i64::try_from(num)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(num.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// Common entry point of every day, so that they can be run from the same process.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let parsed = S::parse(input)?;
+
+    return Ok((S::part1(&parsed), S::part2(&parsed)));
+}